@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use egui_probe::{Probe, angle};
+use egui_probe::{Probe, Style, angle};
 use egui_probe_proc::EguiProbe;
 
 fn main() {
@@ -37,18 +37,15 @@ enum InlinedTags {
     },
 }
 
-#[derive(EguiProbe)]
+#[derive(Default, EguiProbe)]
 #[egui_probe(tags combobox)]
 enum ComboBoxTags {
+    #[default]
     Empty,
 
-    Num { value: usize },
-}
-
-impl Default for ComboBoxTags {
-    fn default() -> Self {
-        ComboBoxTags::Empty
-    }
+    Num {
+        value: usize,
+    },
 }
 
 #[derive(Default, EguiProbe)]
@@ -179,7 +176,12 @@ impl eframe::App for EguiProbeDemoApp {
 
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::vertical().show(ui, |ui| {
-                Probe::new(&mut self.value).show(ui);
+                Probe::new(&mut self.value)
+                    .with_style(Style {
+                        scrub_labels: true,
+                        ..Style::default()
+                    })
+                    .show(ui);
             });
         });
     }
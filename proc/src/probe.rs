@@ -1,6 +1,7 @@
 #![allow(clippy::use_self)]
 
 use convert_case::Casing;
+use quote::ToTokens;
 use syn::{LitStr, parse::Parse, spanned::Spanned};
 
 proc_easy::easy_token!(skip);
@@ -23,12 +24,57 @@ proc_easy::easy_token!(toggle_switch);
 proc_easy::easy_token!(transparent);
 proc_easy::easy_token!(tags);
 proc_easy::easy_token!(inlined);
+proc_easy::easy_token!(radio);
+proc_easy::easy_token!(segmented);
+proc_easy::easy_token!(searchable);
 proc_easy::easy_token!(combobox);
 proc_easy::easy_token!(frozen);
 proc_easy::easy_token!(rgb);
 proc_easy::easy_token!(rgba);
 proc_easy::easy_token!(rgba_premultiplied);
 proc_easy::easy_token!(rgba_unmultiplied);
+proc_easy::easy_token!(decimals);
+proc_easy::easy_token!(prefix);
+proc_easy::easy_token!(suffix);
+proc_easy::easy_token!(hex);
+proc_easy::easy_token!(binary);
+proc_easy::easy_token!(octal);
+proc_easy::easy_token!(percent);
+proc_easy::easy_token!(constrain);
+proc_easy::easy_token!(flatten);
+proc_easy::easy_token!(group);
+proc_easy::easy_token!(tabs);
+proc_easy::easy_token!(visible_if);
+proc_easy::easy_token!(enabled_if);
+proc_easy::easy_token!(on_change);
+proc_easy::easy_token!(order);
+proc_easy::easy_token!(property);
+proc_easy::easy_token!(get);
+proc_easy::easy_token!(set);
+proc_easy::easy_token!(button);
+proc_easy::easy_token!(summary);
+proc_easy::easy_token!(summary_with);
+proc_easy::easy_token!(item_label);
+proc_easy::easy_token!(inline);
+proc_easy::easy_token!(open);
+proc_easy::easy_token!(collapsed);
+proc_easy::easy_token!(no_header);
+proc_easy::easy_token!(hidden);
+proc_easy::easy_token!(disabled);
+proc_easy::easy_token!(construct);
+proc_easy::easy_token!(default);
+proc_easy::easy_token!(remember_variants);
+proc_easy::easy_token!(category);
+proc_easy::easy_token!(finite);
+proc_easy::easy_token!(infinite);
+proc_easy::easy_token!(bytes);
+proc_easy::easy_token!(millis);
+proc_easy::easy_token!(seconds);
+proc_easy::easy_token!(angle);
+proc_easy::easy_token!(degrees);
+proc_easy::easy_token!(radians);
+proc_easy::easy_token!(wrap);
+proc_easy::easy_token!(expression);
 
 proc_easy::easy_parse! {
     #[derive(Clone, Copy)]
@@ -49,7 +95,7 @@ proc_easy::easy_parse! {
 }
 
 proc_easy::easy_parse! {
-    #[allow(unused)]
+    #[allow(unused, clippy::enum_variant_names)]
     #[derive(Clone, Copy)]
     enum RenameCase {
         SnakeCase(snake_case),
@@ -148,6 +194,313 @@ proc_easy::easy_argument_value! {
     }
 }
 
+/// Options of `angle` attribute.
+/// Parsed from optional parenthesized list like `(degrees, range = -180..=180, wrap)`.
+#[derive(Default)]
+struct AngleArgs {
+    degrees: Option<degrees>,
+    radians: Option<radians>,
+    range: Option<syn::Expr>,
+    wrap: Option<wrap>,
+}
+
+impl Parse for AngleArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = AngleArgs::default();
+
+        if !input.peek(syn::token::Paren) {
+            return Ok(args);
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(degrees) {
+                args.degrees = Some(content.parse()?);
+            } else if lookahead.peek(radians) {
+                args.radians = Some(content.parse()?);
+            } else if lookahead.peek(wrap) {
+                args.wrap = Some(content.parse()?);
+            } else if lookahead.peek(range) {
+                content.parse::<range>()?;
+                content.parse::<syn::Token![=]>()?;
+                args.range = Some(content.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        if let (Some(degrees), Some(_)) = (args.degrees, args.radians) {
+            return Err(syn::Error::new_spanned(
+                degrees,
+                "Angle cannot be stored both in degrees and radians",
+            ));
+        }
+
+        Ok(args)
+    }
+}
+
+proc_easy::easy_argument! {
+    struct Angle {
+        angle: angle,
+        args: AngleArgs,
+    }
+}
+
+/// Options of `flatten` attribute.
+/// Parsed from optional parenthesized list like `(prefix)` or `(prefix = "Physics ")`.
+#[derive(Default)]
+struct FlattenArgs {
+    /// Prefix for labels of inner records.
+    /// `Some(None)` means field's name followed by a dot.
+    prefix: Option<Option<syn::LitStr>>,
+}
+
+impl Parse for FlattenArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = FlattenArgs::default();
+
+        if !input.peek(syn::token::Paren) {
+            return Ok(args);
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        if !content.is_empty() {
+            content.parse::<prefix>()?;
+            if content.peek(syn::Token![=]) {
+                content.parse::<syn::Token![=]>()?;
+                args.prefix = Some(Some(content.parse()?));
+            } else {
+                args.prefix = Some(None);
+            }
+        }
+
+        if !content.is_empty() {
+            return Err(content.error("Unexpected tokens in `flatten` options"));
+        }
+
+        Ok(args)
+    }
+}
+
+proc_easy::easy_argument! {
+    struct Flatten {
+        flatten: flatten,
+        args: FlattenArgs,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Summary {
+        summary: summary,
+        /// Format string that may refer to fields by name or index, e.g. `"{name} ({hp} hp)"`.
+        format: syn::LitStr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct SummaryWith {
+        summary_with: summary_with,
+        /// Expression type must implement `FnOnce(&Self) -> T` where `T: Into<RichText>`.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct ItemLabel {
+        item_label: item_label,
+        /// Field that labels the value in collections, type must implement `ToString`.
+        field: syn::Member,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Category {
+        category: category,
+        literal: syn::LitStr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Construct {
+        construct: construct,
+        /// Expression of type `Self`.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct FieldDefault {
+        default: default,
+        /// Expression of field's type.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct VisibleIf {
+        visible_if: visible_if,
+        /// Expression of type `bool`.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct EnabledIf {
+        enabled_if: enabled_if,
+        /// Expression of type `bool`.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct OnChange {
+        on_change: on_change,
+        /// On fields expression type must implement `FnMut(&mut FieldType)` or `FnMut(&mut Self)`.
+        /// On types expression type must implement `FnOnce(&mut Self)`.
+        expr: syn::Expr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct FieldOrder {
+        order: order,
+        literal: syn::LitInt,
+    }
+}
+
+/// List of field names in parentheses, like `(a, c, b)`.
+struct FieldList {
+    fields: syn::punctuated::Punctuated<syn::Member, syn::Token![,]>,
+}
+
+impl Parse for FieldList {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let fields = content.parse_terminated(syn::Member::parse, syn::Token![,])?;
+        Ok(FieldList { fields })
+    }
+}
+
+proc_easy::easy_argument! {
+    struct TypeOrder {
+        order: order,
+        list: FieldList,
+    }
+}
+
+/// Options of `property` attribute.
+/// Parsed from parenthesized list like `(name = "uniform scale", get = Self::scale, set = Self::set_scale)`.
+struct PropertyArgs {
+    name: syn::LitStr,
+    /// Expression type must implement `FnOnce(&Self) -> T`.
+    get: syn::Expr,
+    /// Expression type must implement `FnOnce(&mut Self, T)`.
+    set: syn::Expr,
+}
+
+impl Parse for PropertyArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let mut name_arg = None;
+        let mut get_arg = None;
+        let mut set_arg = None;
+
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(name) {
+                content.parse::<name>()?;
+                content.parse::<syn::Token![=]>()?;
+                name_arg = Some(content.parse()?);
+            } else if lookahead.peek(get) {
+                content.parse::<get>()?;
+                content.parse::<syn::Token![=]>()?;
+                get_arg = Some(content.parse()?);
+            } else if lookahead.peek(set) {
+                content.parse::<set>()?;
+                content.parse::<syn::Token![=]>()?;
+                set_arg = Some(content.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        let missing = |arg: &str| syn::Error::new(span, format!("Property requires `{arg}`"));
+
+        Ok(PropertyArgs {
+            name: name_arg.ok_or_else(|| missing("name"))?,
+            get: get_arg.ok_or_else(|| missing("get"))?,
+            set: set_arg.ok_or_else(|| missing("set"))?,
+        })
+    }
+}
+
+proc_easy::easy_argument! {
+    struct Property {
+        property: property,
+        args: PropertyArgs,
+    }
+}
+
+/// Options of `button` attribute.
+/// Parsed from parenthesized list like `("Respawn", Self::respawn)`.
+struct ButtonArgs {
+    label: syn::LitStr,
+    /// Expression type must implement `FnOnce(&mut Self)`.
+    action: syn::Expr,
+}
+
+impl Parse for ButtonArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let label = content.parse()?;
+        content.parse::<syn::Token![,]>()?;
+        let action = content.parse()?;
+
+        if !content.is_empty() {
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        Ok(ButtonArgs { label, action })
+    }
+}
+
+proc_easy::easy_argument! {
+    struct Button {
+        button: button,
+        args: ButtonArgs,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Group {
+        group: group,
+        literal: syn::LitStr,
+    }
+}
+
 proc_easy::easy_argument_value! {
     struct Name {
         name: name,
@@ -167,6 +520,13 @@ proc_easy::easy_argument_group! {
         Rgba(rgba),
         RgbaPremultiplied(rgba_premultiplied),
         RgbaUnmultiplied(rgba_unmultiplied),
+        Percent(percent),
+        Bytes(bytes),
+        Millis(millis),
+        Seconds(seconds),
+        Angle(Angle),
+        Finite(finite),
+        Infinite(infinite),
     }
 }
 
@@ -183,6 +543,13 @@ impl FieldProbeKind {
             FieldProbeKind::Rgba(rgba) => rgba.span(),
             FieldProbeKind::RgbaPremultiplied(rgba_premultiplied) => rgba_premultiplied.span(),
             FieldProbeKind::RgbaUnmultiplied(rgba_unmultiplied) => rgba_unmultiplied.span(),
+            FieldProbeKind::Percent(percent) => percent.span(),
+            FieldProbeKind::Bytes(bytes) => bytes.span(),
+            FieldProbeKind::Millis(millis) => millis.span(),
+            FieldProbeKind::Seconds(seconds) => seconds.span(),
+            FieldProbeKind::Angle(angle) => angle.angle.span(),
+            FieldProbeKind::Finite(finite) => finite.span(),
+            FieldProbeKind::Infinite(infinite) => infinite.span(),
         }
     }
 
@@ -204,6 +571,78 @@ impl FieldProbeKind {
             FieldProbeKind::Rgba(_) => format_error!("rgba"),
             FieldProbeKind::RgbaPremultiplied(_) => format_error!("rgba_premultiplied"),
             FieldProbeKind::RgbaUnmultiplied(_) => format_error!("rgba_unmultiplied"),
+            FieldProbeKind::Percent(_) => format_error!("percent"),
+            FieldProbeKind::Bytes(_) => format_error!("bytes"),
+            FieldProbeKind::Millis(_) => format_error!("millis"),
+            FieldProbeKind::Seconds(_) => format_error!("seconds"),
+            FieldProbeKind::Angle(_) => format_error!("angle"),
+            FieldProbeKind::Finite(_) => format_error!("finite"),
+            FieldProbeKind::Infinite(_) => format_error!("infinite"),
+        }
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Decimals {
+        decimals: decimals,
+        literal: syn::LitInt,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Prefix {
+        prefix: prefix,
+        literal: syn::LitStr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Suffix {
+        suffix: suffix,
+        literal: syn::LitStr,
+    }
+}
+
+proc_easy::easy_argument_group! {
+    enum RadixKind {
+        Hex(hex),
+        Binary(binary),
+        Octal(octal),
+    }
+}
+
+impl RadixKind {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            RadixKind::Hex(hex) => hex.span(),
+            RadixKind::Binary(binary) => binary.span(),
+            RadixKind::Octal(octal) => octal.span(),
+        }
+    }
+}
+
+proc_easy::easy_argument_group! {
+    enum HeaderKind {
+        Open(open),
+        Collapsed(collapsed),
+        NoHeader(no_header),
+    }
+}
+
+impl HeaderKind {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            HeaderKind::Open(open) => open.span(),
+            HeaderKind::Collapsed(collapsed) => collapsed.span(),
+            HeaderKind::NoHeader(no_header) => no_header.span(),
+        }
+    }
+
+    fn mode(&self) -> proc_macro2::TokenStream {
+        match self {
+            HeaderKind::Open(_) => quote::quote!(::egui_probe::HeaderMode::Open),
+            HeaderKind::Collapsed(_) => quote::quote!(::egui_probe::HeaderMode::Collapsed),
+            HeaderKind::NoHeader(_) => quote::quote!(::egui_probe::HeaderMode::NoHeader),
         }
     }
 }
@@ -216,6 +655,144 @@ proc_easy::easy_attributes! {
         skip: Option<skip>,
         name: Option<Name>,
         kind : Option<FieldProbeKind>,
+        decimals: Option<Decimals>,
+        prefix: Option<Prefix>,
+        suffix: Option<Suffix>,
+        radix: Option<RadixKind>,
+        expression: Option<expression>,
+        flatten: Option<Flatten>,
+        group: Option<Group>,
+        visible_if: Option<VisibleIf>,
+        enabled_if: Option<EnabledIf>,
+        on_change: Option<OnChange>,
+        order: Option<FieldOrder>,
+        header: Option<HeaderKind>,
+        default: Option<FieldDefault>,
+    }
+}
+
+impl FieldAttributes {
+    /// Returns span of the first number formatting attribute, if any.
+    fn number_format_span(&self) -> Option<proc_macro2::Span> {
+        if let Some(decimals) = &self.decimals {
+            return Some(decimals.decimals.span());
+        }
+        if let Some(prefix) = &self.prefix {
+            return Some(prefix.prefix.span());
+        }
+        if let Some(suffix) = &self.suffix {
+            return Some(suffix.suffix.span());
+        }
+        if let Some(radix) = &self.radix {
+            return Some(radix.span());
+        }
+        self.expression.as_ref().map(Spanned::span)
+    }
+
+    /// Returns builder calls that apply number formatting to `EguiProbeRange`.
+    fn number_format(&self) -> proc_macro2::TokenStream {
+        let mut tokens = proc_macro2::TokenStream::new();
+
+        if let Some(decimals) = &self.decimals {
+            let literal = &decimals.literal;
+            tokens.extend(quote::quote!(.decimals(#literal)));
+        }
+        if let Some(prefix) = &self.prefix {
+            let literal = &prefix.literal;
+            tokens.extend(quote::quote!(.prefix(#literal)));
+        }
+        if let Some(suffix) = &self.suffix {
+            let literal = &suffix.literal;
+            tokens.extend(quote::quote!(.suffix(#literal)));
+        }
+        match self.radix {
+            None => {}
+            Some(RadixKind::Hex(_)) => tokens.extend(quote::quote!(.hexadecimal())),
+            Some(RadixKind::Binary(_)) => tokens.extend(quote::quote!(.binary())),
+            Some(RadixKind::Octal(_)) => tokens.extend(quote::quote!(.octal())),
+        }
+        if self.expression.is_some() {
+            tokens.extend(quote::quote!(.expression()));
+        }
+
+        tokens
+    }
+
+    /// Checks attributes of skipped field.
+    /// Returns `true` if field is skipped.
+    fn check_skipped(&self) -> syn::Result<bool> {
+        if self.skip.is_none() {
+            return Ok(false);
+        }
+
+        if let Some(name) = &self.name {
+            return Err(syn::Error::new_spanned(
+                name.name,
+                "Cannot name skipped field",
+            ));
+        }
+
+        if let Some(kind) = &self.kind {
+            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
+        }
+
+        if let Some(span) = self.number_format_span() {
+            return Err(syn::Error::new(
+                span,
+                "Cannot use number formatting attributes for skipped field",
+            ));
+        }
+
+        if let Some(visible_if) = &self.visible_if {
+            return Err(syn::Error::new_spanned(
+                visible_if.visible_if,
+                "Cannot use `visible_if` attribute for skipped field",
+            ));
+        }
+
+        if let Some(enabled_if) = &self.enabled_if {
+            return Err(syn::Error::new_spanned(
+                enabled_if.enabled_if,
+                "Cannot use `enabled_if` attribute for skipped field",
+            ));
+        }
+
+        if let Some(order) = &self.order {
+            return Err(syn::Error::new_spanned(
+                order.order,
+                "Cannot order skipped field",
+            ));
+        }
+
+        if let Some(on_change) = &self.on_change {
+            return Err(syn::Error::new_spanned(
+                on_change.on_change,
+                "Cannot use `on_change` attribute for skipped field",
+            ));
+        }
+
+        if let Some(group) = &self.group {
+            return Err(syn::Error::new_spanned(
+                group.group,
+                "Cannot group skipped field",
+            ));
+        }
+
+        if let Some(flatten) = &self.flatten {
+            return Err(syn::Error::new_spanned(
+                flatten.flatten,
+                "Cannot flatten skipped field",
+            ));
+        }
+
+        if let Some(header) = &self.header {
+            return Err(syn::Error::new(
+                header.span(),
+                "Cannot use header attributes for skipped field",
+            ));
+        }
+
+        Ok(true)
     }
 }
 
@@ -230,6 +807,9 @@ proc_easy::easy_argument_group! {
     enum TagsKind {
         Inlined(inlined),
         ComboBox(combobox),
+        Radio(radio),
+        Segmented(segmented),
+        Searchable(searchable),
     }
 }
 
@@ -240,6 +820,15 @@ proc_easy::easy_argument! {
     }
 }
 
+proc_easy::easy_argument_value! {
+    struct Constrain {
+        constrain: constrain,
+        /// Expression type must implement `FnOnce(&mut Self) -> Result<(), E>`
+        /// where `E` implements `ToString`.
+        expr: syn::Expr,
+    }
+}
+
 proc_easy::easy_attributes! {
     @(egui_probe)
     struct TypeAttributes {
@@ -247,6 +836,18 @@ proc_easy::easy_attributes! {
         where_clause: Option<WhereClause>,
         transparent: Option<transparent>,
         tags: Option<EnumTags>,
+        constrain: Option<Constrain>,
+        tabs: Option<tabs>,
+        on_change: Option<OnChange>,
+        order: Option<TypeOrder>,
+        properties: Vec<Property>,
+        buttons: Vec<Button>,
+        summary: Option<Summary>,
+        summary_with: Option<SummaryWith>,
+        item_label: Option<ItemLabel>,
+        inline: Option<inline>,
+        header: Option<HeaderKind>,
+        remember_variants: Option<remember_variants>,
     }
 }
 
@@ -255,6 +856,11 @@ proc_easy::easy_attributes! {
     struct VariantAttributes {
         name: Option<Name>,
         transparent: Option<transparent>,
+        hidden: Option<hidden>,
+        disabled: Option<disabled>,
+        construct: Option<Construct>,
+        category: Option<Category>,
+        inline: Option<inline>,
     }
 }
 
@@ -276,18 +882,7 @@ fn make_name(
 fn field_name(field: &syn::Field, rename_case: Option<RenameCase>) -> syn::Result<Option<LitStr>> {
     let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
 
-    if attributes.skip.is_some() {
-        if let Some(name) = attributes.name {
-            return Err(syn::Error::new_spanned(
-                name.name,
-                "Cannot name skipped field",
-            ));
-        }
-
-        if let Some(kind) = attributes.kind {
-            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
-        }
-
+    if attributes.check_skipped()? {
         return Ok(None);
     }
 
@@ -296,32 +891,698 @@ fn field_name(field: &syn::Field, rename_case: Option<RenameCase>) -> syn::Resul
     Ok(Some(name))
 }
 
-fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2::TokenStream>> {
-    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
-
-    if attributes.skip.is_some() {
-        if let Some(name) = attributes.name {
-            return Err(syn::Error::new_spanned(
-                name.name,
-                "Cannot name skipped field",
-            ));
-        }
+/// Returns `true` if tokens at `idx` are path separator `::`.
+fn is_path_sep(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
+    let is_colon = |idx: usize| matches!(tokens.get(idx), Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ':');
+    is_colon(idx) && is_colon(idx + 1)
+}
 
-        if let Some(kind) = attributes.kind {
-            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
+/// Collects identifiers in the token stream that may refer to local variables.
+///
+/// Skips identifiers that are path segments, field or method names and called as functions or macros,
+/// e.g. `min` in `Self::min`, `value.min`, `min(a, b)` and `min!()`.
+fn collect_idents(tokens: proc_macro2::TokenStream, idents: &mut Vec<proc_macro2::Ident>) {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                let after_dot = idx > 0
+                    && matches!(&tokens[idx - 1], proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '.');
+                let after_path = idx > 1 && is_path_sep(&tokens, idx - 2);
+                let before_path = is_path_sep(&tokens, idx + 1);
+                let called = match tokens.get(idx + 1) {
+                    Some(proc_macro2::TokenTree::Group(group)) => {
+                        group.delimiter() == proc_macro2::Delimiter::Parenthesis
+                    }
+                    Some(proc_macro2::TokenTree::Punct(punct)) => punct.as_char() == '!',
+                    _ => false,
+                };
+
+                if !(after_dot || after_path || before_path || called) {
+                    idents.push(ident.clone());
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// Wraps expression into a block that binds fields mentioned in it by name.
+/// `bind` returns initializer of the binding from field binding `___N`.
+fn bind_fields(
+    expr: &syn::Expr,
+    fields: &syn::Fields,
+    bind: impl Fn(&proc_macro2::Ident) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut idents = Vec::new();
+    collect_idents(expr.to_token_stream(), &mut idents);
+
+    let bindings: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, field)| {
+            let ident = field.ident.as_ref()?;
+            if !idents.contains(ident) {
+                return None;
+            }
+            let value = bind(&quote::format_ident!("___{}", idx));
+            Some(quote::quote! {
+                #[allow(unused_variables)]
+                let #ident = #value;
+            })
+        })
+        .collect();
+
+    if bindings.is_empty() {
+        expr.to_token_stream()
+    } else {
+        quote::quote!({ #(#bindings)* #expr })
+    }
+}
+
+/// Wraps expression into a block that binds fields mentioned in it by name
+/// to copies of their values.
+/// This allows range and step expressions to refer to other fields of the same struct or variant.
+/// Copies are made before the field is probed, so they do not conflict with its mutable borrow.
+fn with_fields(expr: &syn::Expr, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    bind_fields(
+        expr,
+        fields,
+        |binding| quote::quote!(::core::clone::Clone::clone(&*#binding)),
+    )
+}
+
+/// Wraps condition expression into a block that binds fields mentioned in it by name.
+/// Fields of `Copy` types are bound by value, other fields by shared reference.
+fn with_field_refs(expr: &syn::Expr, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    bind_fields(
+        expr,
+        fields,
+        |binding| quote::quote!((&ConditionField(&*#binding)).condition_value()),
+    )
+}
+
+/// Change tracking for `on_change` callbacks.
+///
+/// Callbacks that accept `&mut Self` are called after all records are emitted,
+/// when `self` is no longer borrowed by field bindings.
+#[derive(Default)]
+struct OnChangeCalls {
+    /// Whether type has `on_change` callback and all records should raise `___on_change` flag.
+    type_flag: bool,
+
+    /// Declarations of flags placed before records.
+    flags: Vec<proc_macro2::TokenStream>,
+
+    /// Calls placed after records.
+    deferred: Vec<proc_macro2::TokenStream>,
+}
+
+/// Wraps record statements so that `flag` is raised when any of emitted records is changed.
+fn record_with_flag(
+    record: proc_macro2::TokenStream,
+    flag: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    quote::quote! {{
+        let _f = &mut |_name: &str, _ui: &mut ::egui_probe::egui::Ui, _value: &mut dyn ::egui_probe::EguiProbe| {
+            _f(_name, _ui, &mut probe_changed(_value, &mut #flag))
+        };
+        #record
+    }}
+}
+
+/// Wraps record statements so that records are emitted into ui with id derived from `key`.
+/// This keeps state of records stable when preceding records are hidden.
+fn record_with_id(
+    record: proc_macro2::TokenStream,
+    kind: &str,
+    key: &str,
+) -> proc_macro2::TokenStream {
+    quote::quote! {
+        _ui.push_id((#kind, #key), |_ui| { #record });
+    }
+}
+
+/// Returns statement that emits record for the field.
+/// Flattened fields emit records of the inner value instead.
+/// Record is skipped or disabled according to `visible_if` and `enabled_if` conditions.
+fn field_record(
+    idx: usize,
+    field: &syn::Field,
+    fields: &syn::Fields,
+    rename_case: Option<RenameCase>,
+    scope: usize,
+    on_change: &mut OnChangeCalls,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let Some(name) = field_name(field, rename_case)? else {
+        return Ok(None);
+    };
+    let Some(probe) = field_probe(idx, field, fields)? else {
+        return Ok(None);
+    };
+
+    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+    if let (Some(_), Some(header)) = (&attributes.flatten, &attributes.header) {
+        return Err(syn::Error::new(
+            header.span(),
+            "Header attributes cannot be used with `flatten`",
+        ));
+    }
+
+    let probe = match &attributes.header {
+        None => probe,
+        Some(header) => {
+            let mode = header.mode();
+            quote::quote!(&mut probe_header(#mode, #probe))
+        }
+    };
+
+    let tokens = match attributes.flatten {
+        None => quote::quote! {
+            _f(#name, _ui, #probe);
+        },
+        Some(Flatten {
+            args: FlattenArgs { prefix: None },
+            ..
+        }) => quote::quote! {
+            flatten_records(#probe, #name, ::core::option::Option::None, _ui, _f);
+        },
+        Some(Flatten {
+            args: FlattenArgs {
+                prefix: Some(prefix),
+            },
+            ..
+        }) => {
+            let prefix =
+                prefix.unwrap_or_else(|| LitStr::new(&format!("{}.", name.value()), name.span()));
+            quote::quote! {
+                flatten_records(#probe, #name, ::core::option::Option::Some(#prefix), _ui, _f);
+            }
+        }
+    };
+
+    let tokens = match attributes.on_change {
+        None => tokens,
+        Some(callback) => {
+            let flag = quote::format_ident!("___on_change_{}_{}", scope, idx);
+            let binding = quote::format_ident!("___{}", idx);
+            let expr = callback.expr;
+            let ty = &field.ty;
+            let record = record_with_flag(tokens, &flag);
+
+            on_change.flags.push(quote::quote! {
+                let mut #flag = false;
+            });
+            on_change.deferred.push(quote::quote! {
+                if #flag {
+                    OnChange::<Self, #ty, _>::call_value(&mut (#expr), self);
+                }
+            });
+
+            quote::quote! {
+                #record
+                if #flag {
+                    #flag = OnChange::<Self, #ty, _>::call_field(&mut (#expr), #binding);
+                }
+            }
+        }
+    };
+
+    let tokens = if on_change.type_flag {
+        record_with_flag(tokens, &quote::format_ident!("___on_change"))
+    } else {
+        tokens
+    };
+
+    let tokens = match attributes.enabled_if {
+        None => tokens,
+        Some(enabled_if) => {
+            let cond = with_field_refs(&enabled_if.expr, fields);
+            quote::quote! {
+                _ui.add_enabled_ui(#cond, |_ui| { #tokens });
+            }
+        }
+    };
+
+    let key = match &field.ident {
+        Some(ident) => ident.to_string(),
+        None => idx.to_string(),
+    };
+    let tokens = record_with_id(tokens, "field", &key);
+
+    let tokens = match attributes.visible_if {
+        None => tokens,
+        Some(visible_if) => {
+            let cond = with_field_refs(&visible_if.expr, fields);
+            quote::quote! {
+                if #cond { #tokens }
+            }
+        }
+    };
+
+    Ok(Some(tokens))
+}
+
+/// Returns statements that show all fields in a single row with small labels.
+///
+/// Statements expect field bindings and set `_changed` flag if any field was changed.
+/// Field callbacks are called when the field's widget reports a change.
+fn inline_fields_probe(
+    fields: &syn::Fields,
+    rename_case: Option<RenameCase>,
+    order: Option<&TypeOrder>,
+    scope: usize,
+    on_change: &mut OnChangeCalls,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut tokens = proc_macro2::TokenStream::new();
+
+    for (idx, field) in sorted_fields(fields, order)? {
+        let Some(name) = field_name(field, rename_case)? else {
+            continue;
+        };
+        let Some(probe) = field_probe(idx, field, fields)? else {
+            continue;
+        };
+
+        let attributes: FieldAttributes =
+            proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+        if let Some(flatten) = attributes.flatten {
+            return Err(syn::Error::new_spanned(
+                flatten.flatten,
+                "Flatten is not supported for inline fields",
+            ));
+        }
+        if let Some(group) = attributes.group {
+            return Err(syn::Error::new_spanned(
+                group.group,
+                "Groups are not supported for inline fields",
+            ));
+        }
+        let field_tokens = if name.value().is_empty() {
+            quote::quote! {
+                let _field_changed = ::egui_probe::EguiProbe::probe(#probe, _ui, _style).changed();
+            }
+        } else {
+            quote::quote! {
+                let _label = _ui.small(#name);
+                let _field_changed = ::egui_probe::EguiProbe::probe(#probe, _ui, _style).labelled_by(_label.id).changed();
+            }
+        };
+
+        let field_tokens = match attributes.on_change {
+            None => quote::quote! {
+                #field_tokens
+                _changed |= _field_changed;
+            },
+            Some(callback) => {
+                let flag = quote::format_ident!("___on_change_{}_{}", scope, idx);
+                let binding = quote::format_ident!("___{}", idx);
+                let expr = callback.expr;
+                let ty = &field.ty;
+
+                on_change.flags.push(quote::quote! {
+                    let mut #flag = false;
+                });
+                on_change.deferred.push(quote::quote! {
+                    if #flag {
+                        OnChange::<Self, #ty, _>::call_value(&mut (#expr), self);
+                    }
+                });
+
+                quote::quote! {
+                    #field_tokens
+                    _changed |= _field_changed;
+                    if _field_changed {
+                        #flag = OnChange::<Self, #ty, _>::call_field(&mut (#expr), #binding);
+                    }
+                }
+            }
+        };
+
+        let field_tokens = match attributes.enabled_if {
+            None => field_tokens,
+            Some(enabled_if) => {
+                let cond = with_field_refs(&enabled_if.expr, fields);
+                quote::quote! {
+                    _ui.add_enabled_ui(#cond, |_ui| { #field_tokens });
+                }
+            }
+        };
+
+        let key = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => idx.to_string(),
+        };
+        let field_tokens = record_with_id(field_tokens, "field", &key);
+
+        let field_tokens = match attributes.visible_if {
+            None => field_tokens,
+            Some(visible_if) => {
+                let cond = with_field_refs(&visible_if.expr, fields);
+                quote::quote! {
+                    if #cond { #field_tokens }
+                }
+            }
+        };
+
+        tokens.extend(field_tokens);
+    }
+
+    Ok(tokens)
+}
+
+/// Returns statements that emit records for properties.
+///
+/// Property value is read through getter into a temporary,
+/// and written back through setter if it was changed.
+fn properties_records(
+    properties: &[Property],
+    on_change: &OnChangeCalls,
+) -> proc_macro2::TokenStream {
+    properties
+        .iter()
+        .map(|property| {
+            let PropertyArgs { name, get, set } = &property.args;
+
+            let record = quote::quote! {
+                _f(#name, _ui, &mut probe_changed(&mut _value, &mut _changed));
+            };
+
+            let record = if on_change.type_flag {
+                record_with_flag(record, &quote::format_ident!("___on_change"))
+            } else {
+                record
+            };
+
+            let record = record_with_id(record, "property", &name.value());
+
+            quote::quote! {{
+                let mut _value = (#get)(&*self);
+                let mut _changed = false;
+                #record
+                if _changed {
+                    (#set)(self, _value);
+                }
+            }}
+        })
+        .collect()
+}
+
+/// Returns statements that emit records for buttons.
+///
+/// Action is called after button was clicked.
+fn buttons_records(buttons: &[Button], on_change: &OnChangeCalls) -> proc_macro2::TokenStream {
+    buttons
+        .iter()
+        .enumerate()
+        .map(|(idx, button)| {
+            let ButtonArgs { label, action } = &button.args;
+
+            let record = quote::quote! {
+                _f("", _ui, &mut probe_button(#label, &mut _clicked));
+            };
+
+            let record = if on_change.type_flag {
+                record_with_flag(record, &quote::format_ident!("___on_change"))
+            } else {
+                record
+            };
+
+            let record = record_with_id(record, "button", &idx.to_string());
+
+            quote::quote! {{
+                let mut _clicked = false;
+                #record
+                if _clicked {
+                    (#action)(self);
+                }
+            }}
+        })
+        .collect()
+}
+
+/// Returns expression that formats summary of the value from its fields.
+///
+/// Placeholders in format string refer to fields by name or index.
+/// Indices are renamed to named arguments since positional arguments must all be used.
+fn summary_format(summary: &Summary) -> syn::Result<proc_macro2::TokenStream> {
+    let value = summary.format.value();
+    let span = summary.format.span();
+
+    let mut format = String::with_capacity(value.len());
+    let mut args = Vec::new();
+    let mut names = Vec::<String>::new();
+
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        format.push(c);
+
+        match c {
+            '{' if chars.peek() == Some(&'{') => format.push(chars.next().unwrap()),
+            '}' if chars.peek() == Some(&'}') => format.push(chars.next().unwrap()),
+            '{' => {
+                let mut field = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c != '_' && !c.is_alphanumeric() {
+                        break;
+                    }
+                    field.push(c);
+                    chars.next();
+                }
+
+                if field.is_empty() {
+                    return Err(syn::Error::new(
+                        span,
+                        "Summary placeholders must refer to a field",
+                    ));
+                }
+
+                let (name, member) = match field.parse::<usize>() {
+                    Ok(index) => (
+                        format!("___{index}"),
+                        syn::Member::Unnamed(syn::Index {
+                            index: index as u32,
+                            span,
+                        }),
+                    ),
+                    Err(_) => (
+                        field.clone(),
+                        syn::Member::Named(syn::Ident::new(&field, span)),
+                    ),
+                };
+
+                format.push_str(&name);
+
+                if !names.contains(&name) {
+                    let ident = syn::Ident::new(&name, span);
+                    args.push(quote::quote!(#ident = &self.#member));
+                    names.push(name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let format = syn::LitStr::new(&format, span);
+    Ok(quote::quote! {
+        ::egui_probe::private::format!(#format, #(#args),*)
+    })
+}
+
+/// Returns fields with indices sorted for display.
+///
+/// Fields listed in type-level `order(...)` come first in listed order,
+/// then other fields. Within those, fields are sorted by `order = N` attribute, which is `0` by default.
+/// Ties keep declaration order.
+fn sorted_fields<'a>(
+    fields: &'a syn::Fields,
+    order: Option<&TypeOrder>,
+) -> syn::Result<Vec<(usize, &'a syn::Field)>> {
+    let listed = order.map_or(0, |order| order.list.fields.len());
+
+    let mut sorted = Vec::new();
+    for (idx, field) in fields.iter().enumerate() {
+        let attributes: FieldAttributes =
+            proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(idx)),
+        };
+
+        let position = order
+            .and_then(|order| order.list.fields.iter().position(|m| *m == member))
+            .unwrap_or(listed);
+
+        let field_order = match attributes.order {
+            None => 0,
+            Some(order) => order.literal.base10_parse::<i64>()?,
+        };
+
+        sorted.push(((position, field_order), idx, field));
+    }
+
+    sorted.sort_by_key(|(key, _, _)| *key);
+    Ok(sorted
+        .into_iter()
+        .map(|(_, idx, field)| (idx, field))
+        .collect())
+}
+
+/// Returns statements that emit records for all fields.
+///
+/// Fields with `group` attribute are emitted as a single record with collapsible section
+/// placed where the first field of the group is shown.
+/// If `tabs` is set, groups are rendered as tabs instead, only selected group is emitted.
+fn fields_records(
+    fields: &syn::Fields,
+    rename_case: Option<RenameCase>,
+    tabs: bool,
+    order: Option<&TypeOrder>,
+    scope: usize,
+    on_change: &mut OnChangeCalls,
+) -> syn::Result<proc_macro2::TokenStream> {
+    enum Entry {
+        Field(proc_macro2::TokenStream),
+        Group(syn::LitStr, Vec<proc_macro2::TokenStream>),
+    }
+
+    let mut entries = Vec::new();
+
+    for (idx, field) in sorted_fields(fields, order)? {
+        let Some(record) = field_record(idx, field, fields, rename_case, scope, on_change)? else {
+            continue;
+        };
+
+        let attributes: FieldAttributes =
+            proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+        match attributes.group {
+            None => entries.push(Entry::Field(record)),
+            Some(group) => {
+                let existing = entries.iter_mut().find_map(|entry| match entry {
+                    Entry::Group(name, records) if name.value() == group.literal.value() => {
+                        Some(records)
+                    }
+                    _ => None,
+                });
+
+                match existing {
+                    Some(records) => records.push(record),
+                    None => entries.push(Entry::Group(group.literal, vec![record])),
+                }
+            }
         }
+    }
+
+    let mut tokens = proc_macro2::TokenStream::new();
+
+    if tabs {
+        let groups: Vec<_> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Group(name, records) => Some((name, records)),
+                Entry::Field(_) => None,
+            })
+            .collect();
+
+        let names = groups.iter().map(|(name, _)| *name);
+        let idxs = 0..groups.len();
+        let records = groups.iter().map(|(_, records)| records);
+
+        let tabs_record = record_with_id(
+            quote::quote! {
+                _f("", _ui, &mut probe_tabs(&[#(#names,)*], &mut _tab));
+            },
+            "tabs",
+            "",
+        );
+
+        let tabs = quote::quote! {
+            let _tabs_id = _ui.id().with("egui_probe_tabs");
+            let mut _tab: usize = _ui.data(|d| d.get_temp(_tabs_id)).unwrap_or(0);
+            #tabs_record
+            _ui.data_mut(|d| d.insert_temp(_tabs_id, _tab));
+
+            match _tab {
+                #(#idxs => { #(#records)* })*
+                _ => {}
+            }
+        };
+
+        let mut tabs = Some(tabs);
+        for entry in entries {
+            match entry {
+                Entry::Field(record) => tokens.extend(record),
+                Entry::Group(..) => tokens.extend(tabs.take()),
+            }
+        }
+    } else {
+        for entry in entries {
+            match entry {
+                Entry::Field(record) => tokens.extend(record),
+                Entry::Group(name, records) => tokens.extend(record_with_id(
+                    quote::quote! {
+                        _f(#name, _ui, &mut probe_group(|_ui, _f| { #(#records)* }));
+                    },
+                    "group",
+                    &name.value(),
+                )),
+            }
+        }
+    }
 
+    Ok(tokens)
+}
+
+fn field_probe(
+    idx: usize,
+    field: &syn::Field,
+    fields: &syn::Fields,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+    if attributes.check_skipped()? {
         return Ok(None);
     }
 
     let binding = quote::format_ident!("___{}", idx);
+    let format = attributes.number_format();
+
+    if let (Some(decimals), Some(_)) = (&attributes.decimals, &attributes.radix) {
+        return Err(syn::Error::new(
+            decimals.decimals.span(),
+            "`decimals` applies only to floating point values and cannot be combined with radix",
+        ));
+    }
+
+    if let Some(span) = attributes.number_format_span() {
+        match attributes.kind {
+            None | Some(FieldProbeKind::Range(_)) => {}
+            Some(_) => {
+                return Err(syn::Error::new(
+                    span,
+                    "Number formatting attributes can be used only with `range` or without other probe kind",
+                ));
+            }
+        }
+    }
 
     let tokens = match attributes.kind {
-        None => {
+        None if format.is_empty() => {
             quote::quote_spanned! {field.span() =>
                 #binding
             }
         }
+        None => {
+            quote::quote_spanned! {field.span() =>
+                &mut probe_range(.., #binding) #format
+            }
+        }
         Some(FieldProbeKind::With(with)) => {
             let expr = with.expr;
             quote::quote_spanned! {field.span() =>
@@ -339,20 +1600,22 @@ fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2
                 unreachable!()
             }
             (Some(range), None) => {
+                let range = with_fields(&range, fields);
                 quote::quote_spanned! {field.span() =>
-                    &mut probe_range(#range, #binding)
+                    &mut probe_range(#range, #binding) #format
                 }
             }
             (None, Some(step)) => {
-                let step = step.expr;
+                let step = with_fields(&step.expr, fields);
                 quote::quote_spanned! {field.span() =>
-                    &mut probe_step(#step, #binding)
+                    &mut probe_step(#step, #binding) #format
                 }
             }
             (Some(range), Some(step)) => {
-                let step = step.expr;
+                let range = with_fields(&range, fields);
+                let step = with_fields(&step.expr, fields);
                 quote::quote_spanned! {field.span() =>
-                    &mut probe_range_step(#range, #step, #binding)
+                    &mut probe_range_step(#range, #step, #binding) #format
                 }
             }
         },
@@ -391,60 +1654,269 @@ fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2
                 &mut probe_rgba_unmultiplied(#binding)
             }
         }
-    };
+        Some(FieldProbeKind::Percent(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::percent(#binding)
+            }
+        }
+        Some(FieldProbeKind::Bytes(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::bytes(#binding)
+            }
+        }
+        Some(FieldProbeKind::Millis(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::millis(#binding)
+            }
+        }
+        Some(FieldProbeKind::Seconds(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::seconds(#binding)
+            }
+        }
+        Some(FieldProbeKind::Finite(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::finite(#binding)
+            }
+        }
+        Some(FieldProbeKind::Infinite(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::infinite(#binding)
+            }
+        }
+        Some(FieldProbeKind::Angle(angle)) => {
+            let mut options = proc_macro2::TokenStream::new();
+            if angle.args.degrees.is_some() {
+                options.extend(quote::quote!(.degrees()));
+            }
+            if let Some(range) = angle.args.range {
+                let range = with_fields(&range, fields);
+                options.extend(quote::quote!(.range(#range)));
+            }
+            if angle.args.wrap.is_some() {
+                options.extend(quote::quote!(.wrap()));
+            }
+            quote::quote_spanned! {field.span() =>
+                &mut ::egui_probe::EguiProbeAngle::new(#binding) #options
+            }
+        }
+    };
+
+    Ok(Some(tokens))
+}
+
+fn variant_selected(
+    variant: &syn::Variant,
+    rename_case: Option<RenameCase>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes: VariantAttributes =
+        proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
+
+    let ident: &proc_macro2::Ident = &variant.ident;
+
+    let name = make_name(attributes.name, Some(ident), rename_case);
+
+    let pattern = match variant.fields {
+        syn::Fields::Unit => quote::quote!(Self::#ident),
+        syn::Fields::Unnamed(_) => quote::quote! {Self::#ident (..)},
+        syn::Fields::Named(_) => quote::quote! {Self::#ident {..}},
+    };
+
+    let tokens = quote::quote_spanned! {variant.ident.span() =>
+        #pattern => #name
+    };
+
+    Ok(tokens)
+}
+
+/// Returns statements that show selectable variants.
+///
+/// Variants of the same category are shown together where the first of them is declared.
+fn variants_probe(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+    rename_case: Option<RenameCase>,
+    remember: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut entries: Vec<(Option<LitStr>, proc_macro2::TokenStream)> = Vec::new();
+
+    for variant in variants {
+        let attributes: VariantAttributes =
+            proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
+
+        let tokens = variant_probe(variant, variants, rename_case, remember)?;
+
+        match attributes.category {
+            None => entries.push((None, tokens)),
+            Some(category) => {
+                let name = category.literal;
+                let existing = entries
+                    .iter_mut()
+                    .find(|(c, _)| c.as_ref().is_some_and(|c| c.value() == name.value()));
+
+                match existing {
+                    None => entries.push((Some(name), tokens)),
+                    Some((_, existing)) => existing.extend(tokens),
+                }
+            }
+        }
+    }
+
+    Ok(entries
+        .into_iter()
+        .map(|(category, tokens)| match category {
+            None => tokens,
+            Some(category) => quote::quote! {
+                _selector.category(_ui, #category, |_ui, _selector| { #tokens });
+            },
+        })
+        .collect())
+}
+
+/// Returns statement that moves fields with same name and type
+/// from the current variant into the newly constructed variant `___new`.
+fn variant_carry_fields(
+    variant: &syn::Variant,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+) -> proc_macro2::TokenStream {
+    let syn::Fields::Named(fields) = &variant.fields else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    let ident = &variant.ident;
+
+    let mut new_fields = Vec::new();
+    let mut arms = Vec::new();
+
+    for other in variants {
+        let syn::Fields::Named(other_fields) = &other.fields else {
+            continue;
+        };
+
+        if other.ident == variant.ident {
+            continue;
+        }
 
-    Ok(Some(tokens))
-}
+        let mut old_fields = Vec::new();
+        let mut swaps = Vec::new();
 
-fn variant_selected(
-    variant: &syn::Variant,
-    rename_case: Option<RenameCase>,
-) -> syn::Result<proc_macro2::TokenStream> {
-    let attributes: VariantAttributes =
-        proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
+        for (idx, field) in fields.named.iter().enumerate() {
+            let name = field.ident.as_ref().unwrap();
+            let ty = field.ty.to_token_stream().to_string();
 
-    let ident: &proc_macro2::Ident = &variant.ident;
+            let same = other_fields.named.iter().any(|other_field| {
+                other_field.ident.as_ref() == Some(name)
+                    && other_field.ty.to_token_stream().to_string() == ty
+            });
 
-    let name = make_name(attributes.name, Some(ident), rename_case);
+            if same {
+                let new_binding = quote::format_ident!("___new_{}", idx);
+                let old_binding = quote::format_ident!("___old_{}", idx);
 
-    let pattern = match variant.fields {
-        syn::Fields::Unit => quote::quote!(Self::#ident),
-        syn::Fields::Unnamed(_) => quote::quote! {Self::#ident (..)},
-        syn::Fields::Named(_) => quote::quote! {Self::#ident {..}},
-    };
+                if !new_fields.iter().any(|(i, _)| *i == idx) {
+                    new_fields.push((idx, quote::quote!(#name: #new_binding)));
+                }
+                old_fields.push(quote::quote!(#name: #old_binding));
+                swaps.push(quote::quote!(::core::mem::swap(#new_binding, #old_binding);));
+            }
+        }
 
-    let tokens = quote::quote_spanned! {variant.ident.span() =>
-        #pattern => #name
-    };
+        if !swaps.is_empty() {
+            let other_ident = &other.ident;
+            arms.push(quote::quote! {
+                Self::#other_ident { #(#old_fields,)* .. } => { #(#swaps)* }
+            });
+        }
+    }
 
-    Ok(tokens)
+    if arms.is_empty() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let new_fields = new_fields.iter().map(|(_, tokens)| tokens);
+
+    quote::quote! {
+        if let Self::#ident { #(#new_fields,)* .. } = &mut ___new {
+            match self {
+                #(#arms)*
+                _ => {}
+            }
+        }
+    }
 }
 
 fn variant_probe(
     variant: &syn::Variant,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
     rename_case: Option<RenameCase>,
+    remember: bool,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let attributes: VariantAttributes =
         proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
 
     let ident = &variant.ident;
 
-    let construct = match variant.fields {
-        syn::Fields::Unit => quote::quote!(Self::#ident),
-        syn::Fields::Unnamed(ref fields) => {
-            let defaults = fields.unnamed.iter().map(|field| {
-                let ty = &field.ty;
-                quote::quote!(<#ty as ::core::default::Default>::default())
-            });
-            quote::quote! {Self::#ident ( #(#defaults,)* )}
+    // Expression of type `Option<Self>` that constructs the variant
+    // and expression that tells whether the variant can be constructed.
+    let (construct, constructible) = match &attributes.construct {
+        Some(construct) => {
+            let expr = &construct.expr;
+            (
+                quote::quote!(::core::option::Option::Some(#expr)),
+                quote::quote!(true),
+            )
         }
-        syn::Fields::Named(ref fields) => {
-            let defaults = fields.named.iter().map(|field| {
-                let ident = field.ident.as_ref().unwrap();
+        None => {
+            let mut values = Vec::new();
+            let mut bindings = Vec::new();
+            let mut constructible = Vec::new();
+
+            for (idx, field) in variant.fields.iter().enumerate() {
+                let field_attributes: FieldAttributes =
+                    proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
                 let ty = &field.ty;
-                quote::quote!(#ident: <#ty as ::core::default::Default>::default())
-            });
-            quote::quote! {Self::#ident { #(#defaults,)* }}
+                match field_attributes.default {
+                    Some(default) => {
+                        let expr = default.expr;
+                        values.push(quote::quote!(::core::option::Option::Some(#expr)));
+                        constructible.push(quote::quote!(true));
+                    }
+                    None => {
+                        values.push(quote::quote! {
+                            (&MaybeDefault::<#ty>::new()).variant_field_default()
+                        });
+                        constructible.push(quote::quote! {
+                            (&MaybeDefault::<#ty>::new()).variant_field_has_default()
+                        });
+                    }
+                }
+                bindings.push(quote::format_ident!("___{}", idx));
+            }
+
+            let value = match variant.fields {
+                syn::Fields::Unit => quote::quote!(Self::#ident),
+                syn::Fields::Unnamed(_) => quote::quote! {Self::#ident ( #(#bindings,)* )},
+                syn::Fields::Named(ref fields) => {
+                    let fields = fields.named.iter().zip(&bindings).map(|(field, binding)| {
+                        let ident = field.ident.as_ref().unwrap();
+                        quote::quote!(#ident: #binding)
+                    });
+                    quote::quote! {Self::#ident { #(#fields,)* }}
+                }
+            };
+
+            let construct = if bindings.is_empty() {
+                quote::quote!(::core::option::Option::Some(#value))
+            } else {
+                quote::quote! {
+                    match (#(#values,)*) {
+                        (#(::core::option::Option::Some(#bindings),)*) => ::core::option::Option::Some(#value),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            };
+
+            (construct, quote::quote!(true #(&& #constructible)*))
         }
     };
 
@@ -456,62 +1928,92 @@ fn variant_probe(
         syn::Fields::Named(_) => quote::quote! {Self::#ident {..}},
     };
 
+    let enabled = match attributes.disabled {
+        None => constructible,
+        Some(_) => quote::quote!(false),
+    };
+
+    let carry = variant_carry_fields(variant, variants);
+    let new_mut = (!carry.is_empty()).then(|| quote::quote!(mut));
+
+    // Remembered state of the variant is used instead of constructing new one,
+    // and the state of the current variant is remembered.
+    let (construct, assign) = if remember {
+        (
+            quote::quote! {
+                match _ui.data_mut(|d| d.get_temp::<Self>(_variants_id.with(#name))) {
+                    ::core::option::Option::Some(remembered) => ::core::option::Option::Some(remembered),
+                    ::core::option::Option::None => #construct,
+                }
+            },
+            quote::quote! {
+                let ___old = ::core::mem::replace(self, ___new);
+                _ui.data_mut(|d| d.insert_temp(_variants_id.with(selected_variant), ___old));
+            },
+        )
+    } else {
+        (construct, quote::quote!(*self = ___new;))
+    };
+
     let tokens = quote::quote_spanned! {variant.ident.span() =>
         #[allow(unreachable_patterns)]
         let checked = match self { #pattern => true, _ => false };
-        if _ui.selectable_label(checked, #name).clicked() && !checked {
-            *self = #construct;
-        }
-        // if _ui.selectable_label(checked, #name).clicked() {
-        //     if !checked {
-        //         *self = #construct;
-        //     }
-        //     if _in_cbox {
-        //         _ui.close_menu();
-        //     }
-        // }
+        if _selector.variant(_ui, checked, #enabled, #name) && !checked {
+            if let ::core::option::Option::Some(#new_mut ___new) = #construct {
+                #carry
+                #assign
+                _changed = true;
+            }
+        }
+    };
+
+    // Hidden variant is shown only when it is the current one.
+    let tokens = match attributes.hidden {
+        None => tokens,
+        Some(_) => quote::quote_spanned! {variant.ident.span() =>
+            #[allow(unreachable_patterns)]
+            if match self { #pattern => true, _ => false } {
+                #tokens
+            }
+        },
     };
 
     Ok(tokens)
 }
 
-fn variant_inline_probe(variant: &syn::Variant) -> syn::Result<proc_macro2::TokenStream> {
+fn variant_inline_probe(
+    variant: &syn::Variant,
+    rename_case: Option<RenameCase>,
+    order: Option<&TypeOrder>,
+    type_inline: bool,
+    scope: usize,
+    on_change: &mut OnChangeCalls,
+) -> syn::Result<proc_macro2::TokenStream> {
     let attributes: VariantAttributes =
         proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
 
     let ident = &variant.ident;
 
-    if attributes.transparent.is_some() {
-        let pattern = match variant.fields {
-            syn::Fields::Unit => quote::quote!(Self::#ident),
-            syn::Fields::Unnamed(ref fields) => {
-                let fields = fields
-                    .unnamed
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, _)| quote::format_ident!("___{}", idx));
-                quote::quote! {Self::#ident ( #(#fields,)* )}
-            }
-            syn::Fields::Named(ref fields) => {
-                let fields = fields.named.iter().enumerate().map(|(idx, field)| {
-                    let binding = quote::format_ident!("___{}", idx);
-                    let ident = field.ident.as_ref().unwrap();
-                    quote::quote!(#ident: #binding)
-                });
-                quote::quote! {Self::#ident { #(#fields,)* }}
-            }
-        };
+    if let (Some(_), Some(inline)) = (&attributes.transparent, &attributes.inline) {
+        return Err(syn::Error::new_spanned(
+            inline,
+            "Transparent variant cannot be inline",
+        ));
+    }
+
+    if let Some(transparent) = attributes.transparent {
+        let pattern = variant_bindings_pattern(variant);
 
         let all_fields_probe: Vec<_> = variant
             .fields
             .iter()
             .enumerate()
-            .filter_map(|(idx, field)| field_probe(idx, field).transpose())
+            .filter_map(|(idx, field)| field_probe(idx, field, &variant.fields).transpose())
             .collect::<syn::Result<_>>()?;
 
         if all_fields_probe.len() != 1 {
             return Err(syn::Error::new_spanned(
-                attributes.transparent.unwrap(),
+                transparent,
                 "Transparent variant must have exactly one non-skipped field",
             ));
         }
@@ -520,11 +2022,21 @@ fn variant_inline_probe(variant: &syn::Variant) -> syn::Result<proc_macro2::Toke
 
         let tokens = quote::quote_spanned! {variant.ident.span() =>
             #pattern => {
-                ::egui_probe::EguiProbe::probe(#field_probe, _ui, _style);
+                _changed |= ::egui_probe::EguiProbe::probe(#field_probe, _ui, _style).changed();
             }
         };
 
         Ok(tokens)
+    } else if (attributes.inline.is_some() || type_inline) && !variant.fields.is_empty() {
+        let pattern = variant_bindings_pattern(variant);
+        let inline_probe =
+            inline_fields_probe(&variant.fields, rename_case, order, scope, on_change)?;
+
+        Ok(quote::quote_spanned! {variant.ident.span() =>
+            #pattern => {
+                #inline_probe
+            }
+        })
     } else {
         let pattern = match variant.fields {
             syn::Fields::Unit => quote::quote!(Self::#ident),
@@ -536,16 +2048,11 @@ fn variant_inline_probe(variant: &syn::Variant) -> syn::Result<proc_macro2::Toke
     }
 }
 
-fn variant_iterate_inner(
-    variant: &syn::Variant,
-    rename_case: Option<RenameCase>,
-) -> syn::Result<proc_macro2::TokenStream> {
-    let attributes: VariantAttributes =
-        proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
-
+/// Returns pattern that binds all fields of the variant to `___{idx}`.
+fn variant_bindings_pattern(variant: &syn::Variant) -> proc_macro2::TokenStream {
     let ident = &variant.ident;
 
-    let pattern = match variant.fields {
+    match variant.fields {
         syn::Fields::Unit => quote::quote!(Self::#ident),
         syn::Fields::Unnamed(ref fields) => {
             let fields = fields
@@ -563,19 +2070,35 @@ fn variant_iterate_inner(
             });
             quote::quote! {Self::#ident { #(#fields,)* }}
         }
-    };
+    }
+}
+
+fn variant_iterate_inner(
+    variant: &syn::Variant,
+    rename_case: Option<RenameCase>,
+    order: Option<&TypeOrder>,
+    type_inline: bool,
+    scope: usize,
+    on_change: &mut OnChangeCalls,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes: VariantAttributes =
+        proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
 
-    if attributes.transparent.is_some() {
+    let ident = &variant.ident;
+
+    let pattern = variant_bindings_pattern(variant);
+
+    if let Some(transparent) = attributes.transparent {
         let all_fields_probe: Vec<_> = variant
             .fields
             .iter()
             .enumerate()
-            .filter_map(|(idx, field)| field_probe(idx, field).transpose())
+            .filter_map(|(idx, field)| field_probe(idx, field, &variant.fields).transpose())
             .collect::<syn::Result<_>>()?;
 
         if all_fields_probe.len() != 1 {
             return Err(syn::Error::new_spanned(
-                attributes.transparent.unwrap(),
+                transparent,
                 "Transparent variant must have exactly one non-skipped field",
             ));
         }
@@ -587,25 +2110,22 @@ fn variant_iterate_inner(
         };
 
         Ok(tokens)
-    } else {
-        let fields_name: Vec<_> = variant
-            .fields
-            .iter()
-            .filter_map(|field| field_name(field, rename_case).transpose())
-            .collect::<syn::Result<_>>()?;
-
-        let fields_probe: Vec<_> = variant
-            .fields
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, field)| field_probe(idx, field).transpose())
-            .collect::<syn::Result<_>>()?;
+    } else if attributes.inline.is_some() || type_inline {
+        // Fields of inline variant are shown next to the selector.
+        let pattern = match variant.fields {
+            syn::Fields::Unit => quote::quote!(Self::#ident),
+            syn::Fields::Unnamed(_) => quote::quote! {Self::#ident (..)},
+            syn::Fields::Named(_) => quote::quote! {Self::#ident {..}},
+        };
 
-        assert_eq!(fields_name.len(), fields_probe.len());
+        Ok(quote::quote! { #pattern => {} })
+    } else {
+        let fields_record =
+            fields_records(&variant.fields, rename_case, false, order, scope, on_change)?;
 
         let tokens = quote::quote_spanned! {variant.ident.span() =>
             #pattern => {
-                #(_f(#fields_name, _ui, #fields_probe);)*
+                #fields_record
             },
         };
 
@@ -635,15 +2155,94 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
         where_clause = Some(&extended_where_clause);
     }
 
+    let constrain = attributes.constrain.map(|constrain| {
+        let expr = constrain.expr;
+        quote::quote! {
+            fn constrain(&mut self) -> ::core::result::Result<(), ::egui_probe::private::String> {
+                (#expr)(self).map_err(|err| ::egui_probe::private::ToString::to_string(&err))
+            }
+        }
+    });
+
+    let mut on_change = OnChangeCalls {
+        type_flag: attributes.on_change.is_some(),
+        ..OnChangeCalls::default()
+    };
+
+    // Type-level callback is called after any record changed.
+    let type_on_change = attributes.on_change.map(|callback| callback.expr);
+    if type_on_change.is_some() {
+        on_change.flags.push(quote::quote! {
+            let mut ___on_change = false;
+        });
+    }
+
+    let item_label = attributes.item_label.as_ref().map(|item_label| {
+        let field = &item_label.field;
+        quote::quote! {
+            fn item_label(&self) -> ::core::option::Option<::egui_probe::private::String> {
+                ::core::option::Option::Some(::egui_probe::private::ToString::to_string(&self.#field))
+            }
+        }
+    });
+
+    let header_mode = attributes.header.as_ref().map(|header| {
+        let mode = header.mode();
+        quote::quote! {
+            fn header_mode(&self) -> ::egui_probe::HeaderMode {
+                #mode
+            }
+        }
+    });
+
+    let properties = properties_records(&attributes.properties, &on_change);
+    let buttons = buttons_records(&attributes.buttons, &on_change);
+
     match input.data {
         syn::Data::Struct(data) => {
-            if attributes.tags.is_some() {
+            if let Some(tags) = attributes.tags {
                 return Err(syn::Error::new_spanned(
-                    attributes.tags.unwrap().tags,
+                    tags.tags,
                     "Tags may be specified only for enums",
                 ));
             }
 
+            if let Some(remember_variants) = attributes.remember_variants {
+                return Err(syn::Error::new_spanned(
+                    remember_variants,
+                    "Remembering variants may be specified only for enums",
+                ));
+            }
+
+            for field in &data.fields {
+                let field_attributes: FieldAttributes =
+                    proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+                if let Some(default) = field_attributes.default {
+                    return Err(syn::Error::new_spanned(
+                        default.default,
+                        "Default may be specified only for fields of enum variants",
+                    ));
+                }
+            }
+
+            if let Some(order) = &attributes.order {
+                for listed in &order.list.fields {
+                    let exists = data
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .any(|(idx, field)| match listed {
+                            syn::Member::Named(ident) => field.ident.as_ref() == Some(ident),
+                            syn::Member::Unnamed(index) => index.index as usize == idx,
+                        });
+
+                    if !exists {
+                        return Err(syn::Error::new_spanned(listed, "Unknown field in `order`"));
+                    }
+                }
+            }
+
             let pattern = match data.fields {
                 syn::Fields::Unit => quote::quote!(Self),
                 syn::Fields::Unnamed(ref fields) => {
@@ -668,13 +2267,48 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                 .fields
                 .iter()
                 .enumerate()
-                .filter_map(|(idx, field)| field_probe(idx, field).transpose())
+                .filter_map(|(idx, field)| field_probe(idx, field, &data.fields).transpose())
                 .collect::<syn::Result<_>>()?;
 
-            if attributes.transparent.is_some() {
+            if let Some(transparent) = attributes.transparent {
+                if let Some(property) = attributes.properties.first() {
+                    return Err(syn::Error::new_spanned(
+                        property.property,
+                        "Properties are not supported for transparent structs",
+                    ));
+                }
+
+                if let Some(button) = attributes.buttons.first() {
+                    return Err(syn::Error::new_spanned(
+                        button.button,
+                        "Buttons are not supported for transparent structs",
+                    ));
+                }
+
+                if let Some(summary) = attributes.summary {
+                    return Err(syn::Error::new_spanned(
+                        summary.summary,
+                        "Summary is not supported for transparent structs",
+                    ));
+                }
+
+                if let Some(inline) = attributes.inline {
+                    return Err(syn::Error::new_spanned(
+                        inline,
+                        "Transparent struct cannot be inline",
+                    ));
+                }
+
+                if let Some(summary_with) = attributes.summary_with {
+                    return Err(syn::Error::new_spanned(
+                        summary_with.summary_with,
+                        "Summary is not supported for transparent structs",
+                    ));
+                }
+
                 if all_fields_probe.len() != 1 {
                     return Err(syn::Error::new_spanned(
-                        attributes.transparent.unwrap(),
+                        transparent,
                         "Transparent struct must have exactly one non-skipped field",
                     ));
                 }
@@ -700,33 +2334,154 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
 
                             ::egui_probe::EguiProbe::iterate_inner(#field_probe, ui, f)
                         }
+
+                        #constrain
+                        #item_label
+                        #header_mode
+                    }
+                };
+                Ok(tokens)
+            } else if let Some(inline) = attributes.inline {
+                if attributes.tabs.is_some()
+                    || !attributes.properties.is_empty()
+                    || !attributes.buttons.is_empty()
+                    || attributes.summary.is_some()
+                    || attributes.summary_with.is_some()
+                {
+                    return Err(syn::Error::new_spanned(
+                        inline,
+                        "Inline struct cannot have tabs, properties, buttons or summary",
+                    ));
+                }
+
+                let mut inline_on_change = OnChangeCalls::default();
+                let inline_probe = inline_fields_probe(
+                    &data.fields,
+                    rename_case,
+                    attributes.order.as_ref(),
+                    0,
+                    &mut inline_on_change,
+                )?;
+                let OnChangeCalls {
+                    flags, deferred, ..
+                } = inline_on_change;
+
+                // Inline struct has no inner records, so it is fixed up as soon as any field changed.
+                // Error is reported when the row of the struct calls `constrain` again.
+                let inline_constrain = constrain.as_ref().map(|_| {
+                    quote::quote! {
+                        if _changed {
+                            let _ = ::egui_probe::EguiProbe::constrain(self);
+                        }
+                    }
+                });
+
+                let type_on_change = type_on_change.map(|expr| {
+                    quote::quote! {
+                        if _changed {
+                            (#expr)(self);
+                        }
+                    }
+                });
+
+                let tokens = quote::quote! {
+                    impl #impl_generics ::egui_probe::EguiProbe for #ident #ty_generics
+                    #where_clause
+                    {
+                        fn probe(&mut self, ui: &mut ::egui_probe::egui::Ui, _style: &::egui_probe::Style) -> ::egui_probe::egui::Response {
+                            use ::egui_probe::private::*;
+
+                            let mut _changed = false;
+                            #(#flags)*
+
+                            let mut r = {
+                                let #pattern = &mut *self;
+
+                                ui.horizontal(|_ui| {
+                                    #inline_probe
+                                }).response
+                            };
+
+                            if _changed {
+                                r.mark_changed();
+                            }
+
+                            #(#deferred)*
+                            #inline_constrain
+                            #type_on_change
+
+                            r
+                        }
+
+                        #constrain
+                        #item_label
+                        #header_mode
                     }
                 };
                 Ok(tokens)
             } else {
-                let fields_name: Vec<_> = data
-                    .fields
-                    .iter()
-                    .filter_map(|field| field_name(field, rename_case).transpose())
-                    .collect::<syn::Result<_>>()?;
+                let fields_record = fields_records(
+                    &data.fields,
+                    rename_case,
+                    attributes.tabs.is_some(),
+                    attributes.order.as_ref(),
+                    0,
+                    &mut on_change,
+                )?;
+
+                let OnChangeCalls {
+                    flags, deferred, ..
+                } = on_change;
+
+                let type_on_change = type_on_change.map(|expr| {
+                    quote::quote! {
+                        if ___on_change {
+                            (#expr)(self);
+                        }
+                    }
+                });
+
+                let summary = match (attributes.summary, attributes.summary_with) {
+                    (None, None) => quote::quote!(::egui_probe::private::stringify!(#ident)),
+                    (Some(summary), None) => summary_format(&summary)?,
+                    (None, Some(summary_with)) => {
+                        let expr = summary_with.expr;
+                        quote::quote!((#expr)(&*self))
+                    }
+                    (Some(_), Some(summary_with)) => {
+                        return Err(syn::Error::new_spanned(
+                            summary_with.summary_with,
+                            "Only one of `summary` and `summary_with` may be specified",
+                        ));
+                    }
+                };
 
                 let tokens = quote::quote! {
                     impl #impl_generics ::egui_probe::EguiProbe for #ident #ty_generics
                     #where_clause
                     {
                         fn probe(&mut self, ui: &mut ::egui_probe::egui::Ui, _style: &::egui_probe::Style) -> ::egui_probe::egui::Response {
-                            ui.weak(::egui_probe::private::stringify!(#ident))
+                            ui.weak(#summary)
                         }
 
                         fn iterate_inner(&mut self, _ui: &mut ::egui_probe::egui::Ui, _f: &mut dyn FnMut(&str, &mut ::egui_probe::egui::Ui, &mut dyn ::egui_probe::EguiProbe)) {
                             use ::egui_probe::private::*;
 
-                            let #pattern = self;
+                            #(#flags)*
+
+                            let #pattern = &mut *self;
 
-                            #(
-                                _f(#fields_name, _ui, #all_fields_probe);
-                            )*
+                            #fields_record
+
+                            #(#deferred)*
+                            #properties
+                            #buttons
+                            #type_on_change
                         }
+
+                        #constrain
+                        #item_label
+                        #header_mode
                     }
                 };
                 Ok(tokens)
@@ -734,47 +2489,125 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
         }
 
         syn::Data::Enum(data) => {
-            if attributes.transparent.is_some() {
+            if let Some(transparent) = attributes.transparent {
                 return Err(syn::Error::new_spanned(
-                    attributes.transparent.unwrap(),
+                    transparent,
                     "Transparent may be specified only for structs or enum variants with exactly one non-skipped field",
                 ));
             }
 
+            if let Some(summary) = attributes.summary {
+                return Err(syn::Error::new_spanned(
+                    summary.summary,
+                    "Summary may be specified only for structs",
+                ));
+            }
+
+            if let Some(item_label) = attributes.item_label {
+                return Err(syn::Error::new_spanned(
+                    item_label.item_label,
+                    "Item label may be specified only for structs",
+                ));
+            }
+
+            if let Some(summary_with) = attributes.summary_with {
+                return Err(syn::Error::new_spanned(
+                    summary_with.summary_with,
+                    "Summary may be specified only for structs",
+                ));
+            }
+
+            if let Some(tabs) = attributes.tabs {
+                return Err(syn::Error::new_spanned(
+                    tabs,
+                    "Tabs may be specified only for structs",
+                ));
+            }
+
             let variants_selected = data
                 .variants
                 .iter()
                 .map(|variant| variant_selected(variant, rename_case))
                 .collect::<syn::Result<Vec<_>>>()?;
 
-            let variants_probe = data
-                .variants
-                .iter()
-                .map(|variant| variant_probe(variant, rename_case))
-                .collect::<syn::Result<Vec<_>>>()?;
+            let variants_probe = variants_probe(
+                &data.variants,
+                rename_case,
+                attributes.remember_variants.is_some(),
+            )?;
 
+            // Inline fields are shown by `probe`, so their callbacks are called there.
+            let mut inline_on_change = OnChangeCalls::default();
             let variants_inline_probe = data
                 .variants
                 .iter()
-                .map(variant_inline_probe)
+                .enumerate()
+                .map(|(scope, variant)| {
+                    variant_inline_probe(
+                        variant,
+                        rename_case,
+                        attributes.order.as_ref(),
+                        attributes.inline.is_some(),
+                        scope,
+                        &mut inline_on_change,
+                    )
+                })
                 .collect::<syn::Result<Vec<_>>>()?;
+            let OnChangeCalls {
+                flags: inline_flags,
+                deferred: inline_deferred,
+                ..
+            } = inline_on_change;
 
             let variants_iterate_inner = data
                 .variants
                 .iter()
-                .map(|variant| variant_iterate_inner(variant, rename_case))
+                .enumerate()
+                .map(|(scope, variant)| {
+                    variant_iterate_inner(
+                        variant,
+                        rename_case,
+                        attributes.order.as_ref(),
+                        attributes.inline.is_some(),
+                        scope,
+                        &mut on_change,
+                    )
+                })
                 .collect::<syn::Result<Vec<_>>>()?;
 
+            let OnChangeCalls {
+                flags, deferred, ..
+            } = on_change;
+
+            let (probe_on_change, type_on_change) = match type_on_change {
+                None => (None, None),
+                Some(expr) => (
+                    Some(quote::quote! {
+                        if _changed {
+                            (#expr)(self);
+                        }
+                    }),
+                    Some(quote::quote! {
+                        if ___on_change {
+                            (#expr)(self);
+                        }
+                    }),
+                ),
+            };
+
             let variants_style = match attributes.tags {
                 None => quote::quote!(_style.variants),
-                Some(EnumTags {
-                    kind: TagsKind::Inlined(_),
-                    ..
-                }) => quote::quote!(::egui_probe::VariantsStyle::Inlined),
-                Some(EnumTags {
-                    kind: TagsKind::ComboBox(_),
-                    ..
-                }) => quote::quote!(::egui_probe::VariantsStyle::ComboBox),
+                Some(EnumTags { kind, .. }) => match kind {
+                    TagsKind::Inlined(_) => quote::quote!(::egui_probe::VariantsStyle::Inlined),
+                    TagsKind::ComboBox(_) => quote::quote!(::egui_probe::VariantsStyle::ComboBox),
+                    TagsKind::Radio(_) => quote::quote!(::egui_probe::VariantsStyle::Radio),
+                    TagsKind::Segmented(_) => {
+                        quote::quote!(::egui_probe::VariantsStyle::Segmented)
+                    }
+                    TagsKind::Searchable(_) => {
+                        quote::quote!(::egui_probe::VariantsStyle::Searchable)
+                    }
+                },
             };
 
             let tokens = quote::quote! {
@@ -784,39 +2617,50 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn probe(&mut self, ui: &mut ::egui_probe::egui::Ui, _style: &::egui_probe::Style) -> ::egui_probe::egui::Response {
                             use ::egui_probe::private::*;
 
-                            ui.horizontal(|_ui| {
-                                match #variants_style {
-                                    ::egui_probe::VariantsStyle::Inlined => {
-                                        let _in_cbox = false;
-                                        #(
-                                            #variants_probe
-                                        )*
-                                    }
-                                    ::egui_probe::VariantsStyle::ComboBox => {
-                                        let selected_variant = match self { #(#variants_selected,)* };
-                                        let cbox = ::egui_probe::egui::ComboBox::from_id_salt(_ui.make_persistent_id("cbox")).selected_text(selected_variant);
-                                        let _in_cbox = true;
-                                        cbox.show_ui(_ui, |_ui| {
-                                            #(
-                                                #variants_probe;
-                                            )*
-                                        });
-                                    }
-                                }
+                            let mut _changed = false;
+                            #(#inline_flags)*
+
+                            // Remembered variants are kept per record that shows this value.
+                            let _variants_id = ui.id().with("egui_probe_variants");
+
+                            let mut r = ui.horizontal(|_ui| {
+                                let selected_variant = match self { #(#variants_selected,)* };
+                                variants_selector(_ui, #variants_style, selected_variant, |_ui, _selector| {
+                                    #variants_probe
+                                });
 
                                 match self {#(
                                     #variants_inline_probe
                                 )*}
-                            }).response
+                            }).response;
+
+                            if _changed {
+                                r.mark_changed();
+                            }
+
+                            #(#inline_deferred)*
+                            #probe_on_change
+
+                            r
                         }
 
                         fn iterate_inner(&mut self, _ui: &mut egui_probe::egui::Ui, _f: &mut dyn FnMut(&str, &mut egui_probe::egui::Ui, &mut dyn ::egui_probe::EguiProbe)) {
                             use ::egui_probe::private::*;
 
-                            match self {#(
+                            #(#flags)*
+
+                            match &mut *self {#(
                                 #variants_iterate_inner
                             )*}
+
+                            #(#deferred)*
+                            #properties
+                            #buttons
+                            #type_on_change
                         }
+
+                        #constrain
+                        #header_mode
                     }
             };
 
@@ -0,0 +1,159 @@
+use core::ops::RangeInclusive;
+
+use crate::{EguiProbe, Style, num::NumberFormat, option::option_probe_with};
+
+/// Unit of the stored angle value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+impl AngleUnit {
+    fn degrees_of(self, value: f64) -> f64 {
+        match self {
+            AngleUnit::Radians => value.to_degrees(),
+            AngleUnit::Degrees => value,
+        }
+    }
+
+    fn value_of(self, degrees: f64) -> f64 {
+        match self {
+            AngleUnit::Radians => degrees.to_radians(),
+            AngleUnit::Degrees => degrees,
+        }
+    }
+}
+
+/// Modifier to edit number as an angle in degrees.
+pub struct EguiProbeAngle<'a, T> {
+    pub value: &'a mut T,
+
+    /// Unit of the stored value.
+    pub unit: AngleUnit,
+
+    /// Allowed range in degrees.
+    pub range: Option<RangeInclusive<f64>>,
+
+    /// If `true`, value that leaves the range is wrapped around instead of clamped.
+    pub wrap: bool,
+}
+
+impl<'a, T> EguiProbeAngle<'a, T> {
+    /// Shows number as an angle in degrees.
+    ///
+    /// Value is stored in radians, call [`EguiProbeAngle::degrees`] if it is stored in degrees.
+    #[inline(always)]
+    pub const fn new(value: &'a mut T) -> Self {
+        EguiProbeAngle {
+            value,
+            unit: AngleUnit::Radians,
+            range: None,
+            wrap: false,
+        }
+    }
+
+    /// Value is stored in radians.
+    #[must_use]
+    pub const fn radians(mut self) -> Self {
+        self.unit = AngleUnit::Radians;
+        self
+    }
+
+    /// Value is stored in degrees.
+    #[must_use]
+    pub const fn degrees(mut self) -> Self {
+        self.unit = AngleUnit::Degrees;
+        self
+    }
+
+    /// Restricts value to the range in degrees.
+    #[must_use]
+    pub fn range<R: Into<f64>>(mut self, range: RangeInclusive<R>) -> Self {
+        let (start, end) = range.into_inner();
+        self.range = Some(start.into()..=end.into());
+        self
+    }
+
+    /// Wraps value around the range instead of clamping.
+    /// Without explicit range value is wrapped around `0..=360` degrees.
+    #[must_use]
+    pub const fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+}
+
+fn drag_degrees(
+    degrees: &mut f64,
+    range: Option<&RangeInclusive<f64>>,
+    wrap: bool,
+    ui: &mut egui::Ui,
+) -> egui::Response {
+    let format = NumberFormat {
+        suffix: "°",
+        ..NumberFormat::new()
+    };
+
+    let mut drag = format.drag_value(degrees).speed(1.0);
+
+    match range {
+        Some(range) if !wrap => {
+            drag = drag.range(range.clone());
+        }
+        _ => {}
+    }
+
+    let mut r = ui.add(drag);
+
+    if wrap && r.changed() {
+        let (start, end) = range.map_or((0.0, 360.0), |range| (*range.start(), *range.end()));
+        let turn = end - start;
+
+        if turn > 0.0 && !(start..=end).contains(degrees) {
+            *degrees = start + (*degrees - start).rem_euclid(turn);
+            r.mark_changed();
+        }
+    }
+
+    r
+}
+
+macro_rules! impl_for_float_types {
+    ($float_type:ident) => {
+        impl EguiProbe for EguiProbeAngle<'_, $float_type> {
+            fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+                let mut degrees = self.unit.degrees_of(f64::from(*self.value));
+                let r = drag_degrees(&mut degrees, self.range.as_ref(), self.wrap, ui);
+
+                if r.changed() {
+                    *self.value = self.unit.value_of(degrees) as $float_type;
+                }
+
+                r
+            }
+        }
+
+        impl EguiProbe for EguiProbeAngle<'_, Option<$float_type>> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                option_probe_with(self.value, ui, style, $float_type::default, |value, ui, _| {
+                    let mut degrees = self.unit.degrees_of(f64::from(*value));
+                    let r = drag_degrees(&mut degrees, self.range.as_ref(), self.wrap, ui);
+
+                    if r.changed() {
+                        *value = self.unit.value_of(degrees) as $float_type;
+                    }
+
+                    r
+                })
+            }
+        }
+    };
+
+    ($($float_type:ident),*) => {
+        $(impl_for_float_types!($float_type);)*
+    };
+}
+
+impl_for_float_types!(f32, f64);
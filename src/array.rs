@@ -1,4 +1,4 @@
-use crate::EguiProbe;
+use crate::{EguiProbe, collections::item_label};
 
 impl<T, const N: usize> EguiProbe for [T; N]
 where
@@ -14,7 +14,7 @@ where
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
         for (i, value) in self.iter_mut().enumerate() {
-            f(&format!("[{i}]"), ui, value);
+            f(&item_label(i, &*value), ui, value);
         }
     }
 }
@@ -33,7 +33,7 @@ where
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
         for (i, value) in self.iter_mut().enumerate() {
-            f(&format!("[{i}]"), ui, value);
+            f(&item_label(i, &*value), ui, value);
         }
     }
 }
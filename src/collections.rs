@@ -1,4 +1,4 @@
-use crate::{EguiProbe, Style};
+use crate::{EguiProbe, HeaderMode, Style};
 
 /// Modifier to add a delete button to an item probe UI.
 pub struct DeleteMe<'a, T> {
@@ -35,9 +35,137 @@ where
     ) {
         self.value.iterate_inner(ui, f);
     }
+
+    fn can_scrub(&self) -> bool {
+        self.value.can_scrub()
+    }
+
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        self.value.scrub(delta)
+    }
+
+    fn constrain(&mut self) -> Result<(), String> {
+        self.value.constrain()
+    }
+
+    fn item_label(&self) -> Option<String> {
+        self.value.item_label()
+    }
+
+    fn header_mode(&self) -> HeaderMode {
+        self.value.header_mode()
+    }
+}
+
+/// Stable keys of items of a growable collection.
+///
+/// Items are emitted into uis with ids derived from their keys,
+/// so that state of items, e.g. open headers and remembered variants,
+/// stays with them when preceding items are removed.
+#[derive(Clone, Default)]
+pub(crate) struct ItemKeys {
+    keys: Vec<u64>,
+    next: u64,
+    removed: Vec<u64>,
+}
+
+impl ItemKeys {
+    /// Loads keys for collection with `len` items shown in the `ui`.
+    /// Items added since the last frame get new keys.
+    pub fn load(ui: &egui::Ui, len: usize) -> Self {
+        let mut keys: ItemKeys = ui
+            .data_mut(|d| d.get_temp(Self::id(ui)))
+            .unwrap_or_default();
+
+        keys.keys.truncate(len);
+        while keys.keys.len() < len {
+            keys.keys.push(keys.next);
+            keys.next += 1;
+        }
+
+        keys
+    }
+
+    /// Stores keys of items that were not removed.
+    pub fn store(mut self, ui: &egui::Ui) {
+        let removed = core::mem::take(&mut self.removed);
+        self.keys.retain(|key| !removed.contains(key));
+        ui.data_mut(|d| d.insert_temp(Self::id(ui), self));
+    }
+
+    /// Returns key of the item at `idx` as it was before any removal in this frame.
+    pub fn get(&self, idx: usize) -> u64 {
+        self.keys[idx]
+    }
+
+    pub fn remove(&mut self, key: u64) {
+        self.removed.push(key);
+    }
+
+    fn id(ui: &egui::Ui) -> egui::Id {
+        ui.id().with("egui_probe_item_keys")
+    }
+}
+
+/// Returns label of collection's item at `idx`, e.g. `[3] Goblin`.
+///
+/// See [`EguiProbe::item_label`].
+pub(crate) fn item_label<T>(idx: usize, value: &T) -> String
+where
+    T: EguiProbe + ?Sized,
+{
+    match value.item_label() {
+        None => format!("[{idx}]"),
+        Some(label) => format!("[{idx}] {label}"),
+    }
 }
 
 /// Modifier to disable adding/removing items from collections.
 pub struct EguiProbeFrozen<'a, T> {
     pub value: &'a mut T,
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::ItemKeys;
+
+    /// Runs a frame with `f` called for the ui of the central panel.
+    pub(crate) fn with_ui(ctx: &egui::Context, f: impl FnOnce(&mut egui::Ui)) {
+        with_input(ctx, egui::RawInput::default(), f);
+    }
+
+    /// Runs a frame with given input and `f` called for the ui of the central panel.
+    pub(crate) fn with_input(
+        ctx: &egui::Context,
+        input: egui::RawInput,
+        f: impl FnOnce(&mut egui::Ui),
+    ) {
+        let mut f = Some(f);
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| f.take().unwrap()(ui));
+        });
+    }
+
+    #[test]
+    fn item_keys_follow_items() {
+        let ctx = egui::Context::default();
+
+        with_ui(&ctx, |ui| {
+            let keys = ItemKeys::load(ui, 3);
+            assert_eq!((keys.get(0), keys.get(1), keys.get(2)), (0, 1, 2));
+            keys.store(ui);
+        });
+
+        with_ui(&ctx, |ui| {
+            let mut keys = ItemKeys::load(ui, 3);
+            keys.remove(keys.get(1));
+            keys.store(ui);
+        });
+
+        with_ui(&ctx, |ui| {
+            let keys = ItemKeys::load(ui, 3);
+            assert_eq!((keys.get(0), keys.get(1), keys.get(2)), (0, 2, 3));
+            keys.store(ui);
+        });
+    }
+}
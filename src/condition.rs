@@ -0,0 +1,72 @@
+/// Field referred to by name in `visible_if` and `enabled_if` conditions.
+///
+/// Uses autoref-based specialization: `(&ConditionField(&field)).condition_value()`
+/// resolves to [`ViaCopy`] that returns copy of the field if it implements [`Copy`],
+/// and to [`ViaRef`] that returns shared reference otherwise.
+pub struct ConditionField<'a, T>(pub &'a T);
+
+pub trait ViaCopy<T> {
+    fn condition_value(&self) -> T;
+}
+
+impl<T> ViaCopy<T> for ConditionField<'_, T>
+where
+    T: Copy,
+{
+    #[inline(always)]
+    fn condition_value(&self) -> T {
+        *self.0
+    }
+}
+
+pub trait ViaRef<'a, T> {
+    fn condition_value(&self) -> &'a T;
+}
+
+impl<'a, T> ViaRef<'a, T> for &ConditionField<'a, T> {
+    #[inline(always)]
+    fn condition_value(&self) -> &'a T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EguiProbe, collections::tests::with_ui};
+
+    #[derive(EguiProbe)]
+    struct Light {
+        enabled: bool,
+        #[egui_probe(visible_if = enabled)]
+        intensity: f32,
+        #[egui_probe(visible_if = !name.is_empty())]
+        name: String,
+    }
+
+    fn record_names(ctx: &egui::Context, value: &mut dyn EguiProbe) -> Vec<String> {
+        let mut names = Vec::new();
+        with_ui(ctx, |ui| {
+            value.iterate_inner(ui, &mut |name, _ui, _value| names.push(name.to_owned()));
+        });
+        names
+    }
+
+    #[test]
+    fn hidden_fields_emit_no_records() {
+        let ctx = egui::Context::default();
+        let mut light = Light {
+            enabled: false,
+            intensity: 1.0,
+            name: String::new(),
+        };
+
+        assert_eq!(record_names(&ctx, &mut light), ["enabled"]);
+
+        light.enabled = true;
+        light.name = "Sun".to_owned();
+        assert_eq!(
+            record_names(&ctx, &mut light),
+            ["enabled", "intensity", "name"]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Evaluator of small arithmetic expressions typed into numeric fields.
+//!
+//! Supports `+`, `-`, `*`, `/`, `%`, `^`, parentheses,
+//! constants `pi`, `tau`, `e`, functions like `sqrt(x)` and `sin(x)`,
+//! integer literals in `0x`, `0b` and `0o` radix,
+//! and compound assignments `+=`, `-=`, `*=`, `/=` applied to the current value.
+
+/// Evaluates expression.
+/// `current` is the value compound assignments are applied to.
+pub fn evaluate(text: &str, current: f64) -> Result<f64, String> {
+    let text = text.trim();
+
+    let (op, text) = match text.as_bytes() {
+        [op @ (b'+' | b'-' | b'*' | b'/'), b'=', ..] => (Some(*op), &text[2..]),
+        _ => (None, text),
+    };
+
+    let mut parser = Parser { text, pos: 0 };
+
+    let value = parser.expr()?;
+    parser.skip_whitespace();
+
+    if let Some(c) = parser.peek() {
+        return Err(format!("Unexpected `{c}`"));
+    }
+
+    let value = match op {
+        None => value,
+        Some(b'+') => current + value,
+        Some(b'-') => current - value,
+        Some(b'*') => current * value,
+        Some(b'/') => current / value,
+        Some(_) => unreachable!(),
+    };
+
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err("Result is not a finite number".to_owned())
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Consumes next non-whitespace character if it is one of `chars`.
+    fn eat(&mut self, chars: &[char]) -> Option<char> {
+        self.skip_whitespace();
+        let c = self.peek()?;
+        if chars.contains(&c) {
+            self.pos += c.len_utf8();
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    fn expr(&mut self) -> Result<f64, String> {
+        let mut value = self.term()?;
+        while let Some(op) = self.eat(&['+', '-', '\u{2212}']) {
+            let rhs = self.term()?;
+            if op == '+' {
+                value += rhs;
+            } else {
+                value -= rhs;
+            }
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<f64, String> {
+        let mut value = self.unary()?;
+        while let Some(op) = self.eat(&['*', '/', '%']) {
+            let rhs = self.unary()?;
+            match op {
+                '*' => value *= rhs,
+                '/' => value /= rhs,
+                _ => value %= rhs,
+            }
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        match self.eat(&['+', '-', '\u{2212}']) {
+            Some('+') => self.unary(),
+            Some(_) => Ok(-self.unary()?),
+            None => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<f64, String> {
+        let base = self.atom()?;
+        if self.eat(&['^']).is_some() {
+            let exp = self.unary()?;
+            Ok(base.powf(exp))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn atom(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            None => Err("Unexpected end of expression".to_owned()),
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expr()?;
+                if self.eat(&[')']).is_none() {
+                    return Err("Expected `)`".to_owned());
+                }
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) if c.is_alphabetic() || c == '_' => self.name(),
+            Some(c) => Err(format!("Unexpected `{c}`")),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        let rest = &self.text[self.pos..];
+
+        let radix = match rest.get(..2) {
+            Some("0x" | "0X") => 16,
+            Some("0b" | "0B") => 2,
+            Some("0o" | "0O") => 8,
+            _ => 10,
+        };
+
+        if radix != 10 {
+            let digits = &rest[2..];
+            let len = digits
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(digits.len());
+            let value = u64::from_str_radix(&digits[..len], radix)
+                .map_err(|_| format!("Invalid number `{}`", &rest[..2 + len]))?;
+            self.pos += 2 + len;
+            return Ok(value as f64);
+        }
+
+        let mut len = 0;
+        let mut prev = '\0';
+        for c in rest.chars() {
+            let exponent_sign = (c == '+' || c == '-') && (prev == 'e' || prev == 'E');
+            if !(c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || exponent_sign) {
+                break;
+            }
+            prev = c;
+            len += c.len_utf8();
+        }
+
+        // Trailing `e` without exponent digits is not part of the number.
+        if matches!(prev, 'e' | 'E') {
+            len -= 1;
+        }
+
+        let value = rest[..len]
+            .parse()
+            .map_err(|_| format!("Invalid number `{}`", &rest[..len]))?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    fn name(&mut self) -> Result<f64, String> {
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let name = &rest[..len];
+        self.pos += len;
+
+        let function: fn(f64) -> f64 = match name {
+            "pi" | "PI" | "\u{3c0}" => return Ok(core::f64::consts::PI),
+            "tau" | "TAU" | "\u{3c4}" => return Ok(core::f64::consts::TAU),
+            "e" | "E" => return Ok(core::f64::consts::E),
+            "sqrt" => f64::sqrt,
+            "abs" => f64::abs,
+            "floor" => f64::floor,
+            "ceil" => f64::ceil,
+            "round" => f64::round,
+            "sin" => f64::sin,
+            "cos" => f64::cos,
+            "tan" => f64::tan,
+            "ln" => f64::ln,
+            "log" => f64::log10,
+            "exp" => f64::exp,
+            "rad" => f64::to_radians,
+            "deg" => f64::to_degrees,
+            _ => return Err(format!("Unknown name `{name}`")),
+        };
+
+        if self.eat(&['(']).is_none() {
+            return Err(format!("Expected `(` after `{name}`"));
+        }
+        let arg = self.expr()?;
+        if self.eat(&[')']).is_none() {
+            return Err("Expected `)`".to_owned());
+        }
+
+        Ok(function(arg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluate;
+
+    fn eval(text: &str) -> f64 {
+        evaluate(text, 0.0).unwrap()
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval("1 + 2 * 3"), 7.0);
+        assert_eq!(eval("(1 + 2) * 3"), 9.0);
+        assert_eq!(eval("10 - 4 - 3"), 3.0);
+        assert_eq!(eval("12 / 3 / 2"), 2.0);
+        assert_eq!(eval("7 % 4 * 2"), 6.0);
+        assert_eq!(eval("2 * 3 ^ 2"), 18.0);
+        assert_eq!(eval("1 \u{2212} 3"), -2.0);
+    }
+
+    #[test]
+    fn power_is_right_associative() {
+        assert_eq!(eval("2 ^ 3 ^ 2"), 512.0);
+        assert_eq!(eval("(2 ^ 3) ^ 2"), 64.0);
+    }
+
+    #[test]
+    fn unary_minus_and_power() {
+        assert_eq!(eval("-2 ^ 2"), -4.0);
+        assert_eq!(eval("(-2) ^ 2"), 4.0);
+        assert_eq!(eval("2 ^ -1"), 0.5);
+        assert_eq!(eval("--3"), 3.0);
+        assert_eq!(eval("+-3"), -3.0);
+    }
+
+    #[test]
+    fn compound_assignment() {
+        assert_eq!(evaluate("+= 5", 10.0), Ok(15.0));
+        assert_eq!(evaluate("-=5", 10.0), Ok(5.0));
+        assert_eq!(evaluate("*= 2 + 1", 10.0), Ok(30.0));
+        assert_eq!(evaluate("/= 4", 10.0), Ok(2.5));
+        assert_eq!(evaluate("5", 10.0), Ok(5.0));
+    }
+
+    #[test]
+    fn radix_literals() {
+        assert_eq!(eval("0xFF"), 255.0);
+        assert_eq!(eval("0x10 + 0b101"), 21.0);
+        assert_eq!(eval("0o17"), 15.0);
+        assert_eq!(eval("-0B11"), -3.0);
+        assert!(evaluate("0xG", 0.0).is_err());
+        assert!(evaluate("0b2", 0.0).is_err());
+    }
+
+    #[test]
+    fn exponents() {
+        assert_eq!(eval("1e3"), 1000.0);
+        assert_eq!(eval("2.5E-2"), 0.025);
+        assert_eq!(eval("1e+2 * 2"), 200.0);
+        assert!(evaluate("2e", 0.0).is_err());
+        assert!(evaluate("1e-", 0.0).is_err());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(eval("2 * pi"), core::f64::consts::TAU);
+        assert_eq!(eval("sqrt(16) + abs(-1)"), 5.0);
+        assert_eq!(eval("deg(pi)"), 180.0);
+    }
+
+    #[test]
+    fn malformed() {
+        for text in [
+            "", "1 +", "(1", "1)", "2 * * 3", "foo", "sqrt 4", "sqrt(4", "1..2", "+=",
+        ] {
+            assert!(evaluate(text, 1.0).is_err(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(evaluate("1 / 0", 0.0).is_err());
+        assert!(evaluate("0 / 0", 0.0).is_err());
+        assert!(evaluate("/= 0", 5.0).is_err());
+        assert!(evaluate("ln(0)", 0.0).is_err());
+    }
+}
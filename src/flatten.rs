@@ -0,0 +1,114 @@
+use crate::{
+    EguiProbe, Style,
+    on_change::EguiProbeChanged,
+    probe_fn,
+    widget::{load_error, store_error},
+};
+
+/// Emits records of the flattened `value` as records of the parent.
+///
+/// Runs [`EguiProbe::constrain`] of the value when any of its records changed
+/// and shows its error in a separate record.
+/// Value without inner records, e.g. a number or an empty collection,
+/// is emitted as a normal record named `name`.
+pub fn flatten_records(
+    value: &mut dyn EguiProbe,
+    name: &str,
+    prefix: Option<&str>,
+    ui: &mut egui::Ui,
+    f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
+) {
+    let mut emitted = false;
+    let mut changed = false;
+
+    value.iterate_inner(ui, &mut |inner_name, ui, value| {
+        emitted = true;
+        let value = &mut EguiProbeChanged {
+            value,
+            changed: &mut changed,
+        };
+        match prefix {
+            None => f(inner_name, ui, value),
+            Some(prefix) => f(&format!("{prefix}{inner_name}"), ui, value),
+        }
+    });
+
+    if !emitted {
+        f(name, ui, value);
+        return;
+    }
+
+    if changed {
+        store_error(ui.ctx(), ui.id(), value.constrain().err());
+    }
+
+    if let Some(error) = load_error(ui.ctx(), ui.id()) {
+        let mut error = probe_fn(|ui: &mut egui::Ui, _style: &Style| {
+            ui.colored_label(ui.visuals().error_fg_color, &error)
+        });
+        f("", ui, &mut error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EguiProbe, collections::tests::with_ui};
+
+    #[derive(EguiProbe)]
+    #[egui_probe(constrain = Self::order)]
+    struct Span {
+        start: f32,
+        end: f32,
+    }
+
+    impl Span {
+        fn order(&mut self) -> Result<(), &'static str> {
+            if self.end < self.start {
+                self.end = self.start;
+                return Err("End was before start");
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(EguiProbe)]
+    struct Clip {
+        #[egui_probe(flatten)]
+        span: Span,
+
+        #[egui_probe(flatten(prefix))]
+        speed: f32,
+    }
+
+    #[test]
+    fn flattened_value_is_constrained() {
+        let ctx = egui::Context::default();
+        let mut clip = Clip {
+            span: Span {
+                start: 0.0,
+                end: 5.0,
+            },
+            speed: 1.0,
+        };
+
+        let mut names = Vec::new();
+        with_ui(&ctx, |ui| {
+            clip.iterate_inner(ui, &mut |name, _ui, value| {
+                names.push(name.to_owned());
+                if name == "start" {
+                    assert!(value.scrub(&mut 10.0));
+                }
+            });
+        });
+
+        assert_eq!(names, ["start", "end", "", "speed"]);
+        assert_eq!((clip.span.start, clip.span.end), (10.0, 10.0));
+
+        names.clear();
+        with_ui(&ctx, |ui| {
+            clip.iterate_inner(ui, &mut |name, _ui, _value| names.push(name.to_owned()));
+        });
+
+        assert_eq!(names, ["start", "end", "", "speed"]);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{EguiProbe, Style, num::non_finite_badge, option::option_probe_with};
+
+/// Modifier to edit floating-point value with explicit handling of NaN and infinity.
+pub struct EguiProbeFloat<'a, T> {
+    pub value: &'a mut T,
+
+    /// If `true`, value may be set to positive or negative infinity,
+    /// e.g. to represent unlimited value.
+    /// Otherwise value must be finite and non-finite value is shown as an error.
+    pub infinite: bool,
+}
+
+/// `max` is the largest finite value of the float type.
+fn float_probe(value: &mut f64, max: f64, infinite: bool, ui: &mut egui::Ui) -> egui::Response {
+    if value.is_nan() || (value.is_infinite() && !infinite) {
+        let color = ui.visuals().error_fg_color;
+        return non_finite_badge(value, 0.0, color, ui);
+    }
+
+    if value.is_infinite() {
+        return non_finite_badge(value, 0.0, ui.visuals().strong_text_color(), ui);
+    }
+
+    let mut changed = false;
+    let mut r = ui
+        .horizontal(|ui| {
+            let old = *value;
+            if ui
+                .add(egui::DragValue::new(value).range(-max..=max))
+                .changed()
+            {
+                if value.is_finite() {
+                    changed = true;
+                } else {
+                    *value = old;
+                }
+            }
+
+            if infinite {
+                if ui.small_button("-∞").clicked() {
+                    *value = f64::NEG_INFINITY;
+                    changed = true;
+                }
+                if ui.small_button("+∞").clicked() {
+                    *value = f64::INFINITY;
+                    changed = true;
+                }
+            }
+        })
+        .response;
+
+    if changed {
+        r.mark_changed();
+    }
+
+    r
+}
+
+macro_rules! impl_for_float_types {
+    ($float_type:ident) => {
+        impl EguiProbe for EguiProbeFloat<'_, $float_type> {
+            fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+                let mut value = f64::from(*self.value);
+                let r = float_probe(&mut value, $float_type::MAX.into(), self.infinite, ui);
+
+                if r.changed() {
+                    *self.value = value as $float_type;
+                }
+
+                r
+            }
+        }
+
+        impl EguiProbe for EguiProbeFloat<'_, Option<$float_type>> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                option_probe_with(self.value, ui, style, $float_type::default, |value, ui, _| {
+                    let mut v = f64::from(*value);
+                    let r = float_probe(&mut v, $float_type::MAX.into(), self.infinite, ui);
+
+                    if r.changed() {
+                        *value = v as $float_type;
+                    }
+
+                    r
+                })
+            }
+        }
+    };
+
+    ($($float_type:ident),*) => {
+        $(impl_for_float_types!($float_type);)*
+    };
+}
+
+impl_for_float_types!(f32, f64);
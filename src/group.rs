@@ -0,0 +1,114 @@
+use crate::{EguiProbe, HeaderMode, Style};
+
+/// Probe-able section that shows records produced by a function.
+///
+/// Used to render group of fields under collapsible header.
+pub struct EguiProbeGroup<F>(pub F);
+
+impl<F> EguiProbe for EguiProbeGroup<F>
+where
+    F: FnMut(&mut egui::Ui, &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe)),
+{
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.allocate_response(egui::Vec2::ZERO, egui::Sense::hover())
+    }
+
+    #[inline(always)]
+    fn iterate_inner(
+        &mut self,
+        ui: &mut egui::Ui,
+        f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
+    ) {
+        (self.0)(ui, f);
+    }
+}
+
+/// Bar of tabs to select one of the groups.
+pub struct EguiProbeTabs<'a> {
+    pub tabs: &'a [&'a str],
+    pub selected: &'a mut usize,
+}
+
+impl EguiProbe for EguiProbeTabs<'_> {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        // Selected tab is UI state, switching it does not change the value.
+        ui.horizontal(|ui| {
+            for (idx, tab) in self.tabs.iter().enumerate() {
+                let checked = *self.selected == idx;
+                if ui.selectable_label(checked, *tab).clicked() {
+                    *self.selected = idx;
+                }
+            }
+        })
+        .response
+    }
+}
+
+/// Button that raises a flag when clicked.
+///
+/// Used to render action records that call a method of the value.
+pub struct EguiProbeButton<'a> {
+    pub label: &'a str,
+    pub clicked: &'a mut bool,
+}
+
+impl EguiProbe for EguiProbeButton<'_> {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        let mut r = ui.button(self.label);
+
+        if r.clicked() {
+            *self.clicked = true;
+            r.mark_changed();
+        }
+
+        r
+    }
+}
+
+/// Modifier to override how record with inner records is shown.
+pub struct EguiProbeHeader<'a> {
+    pub value: &'a mut dyn EguiProbe,
+    pub mode: HeaderMode,
+}
+
+impl EguiProbe for EguiProbeHeader<'_> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        self.value.probe(ui, style)
+    }
+
+    #[inline(always)]
+    fn iterate_inner(
+        &mut self,
+        ui: &mut egui::Ui,
+        f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
+    ) {
+        self.value.iterate_inner(ui, f);
+    }
+
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        self.value.can_scrub()
+    }
+
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        self.value.scrub(delta)
+    }
+
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        self.value.constrain()
+    }
+
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        self.value.item_label()
+    }
+
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        self.mode
+    }
+}
@@ -75,9 +75,35 @@
 //!
 //! - `#[egui_probe(toggle_switch)]`: Render a boolean as a toggle switch.
 //! - `#[egui_probe(range = 22..=55)]`: Specify a range for numeric values.
+//! - `#[egui_probe(range = min_speed..)]`: Range and step may refer to other fields by name.
 //! - `#[egui_probe(as angle)]`: Render a float as an angle.
+//! - `#[egui_probe(angle(degrees, range = -180..=180, wrap))]`: Render a float as an angle with options.
 //! - `#[egui_probe(name = "custom name")]`: Rename the field in the UI.
 //! - `#[egui_probe(multiline)]`: Render a string as a multiline text box.
+//! - `#[egui_probe(decimals = 2, suffix = " m/s")]`: Format numeric values.
+//! - `#[egui_probe(expression)]`: Edit numeric values as arithmetic expressions, e.g. `2*pi` or `+=5`.
+//! - `#[egui_probe(percent)]`, `#[egui_probe(bytes)]`, `#[egui_probe(millis)]`, `#[egui_probe(seconds)]`: Render a number in human units.
+//! - `#[egui_probe(finite)]`: Require a float to be finite, NaN and infinity are shown as an error with a reset button.
+//! - `#[egui_probe(infinite)]`: Allow setting a float to positive or negative infinity.
+//! - `#[egui_probe(flatten)]`: Show records of the field's value directly in the parent's table.
+//! - `#[egui_probe(flatten(prefix = "Physics "))]`: Same with prefix for labels, `flatten(prefix)` uses field's name.
+//! - `#[egui_probe(group = "Physics")]`: Show the field in a collapsible section, `#[egui_probe(tabs)]` on a struct shows sections as tabs.
+//! - `#[egui_probe(visible_if = expr)]`, `#[egui_probe(enabled_if = expr)]`: Hide or disable the field depending on other fields.
+//! - `#[egui_probe(on_change = Self::rebuild)]`: Call a function with the field or the whole value after the field changed, also on types.
+//! - `#[egui_probe(order = 1)]`: Sort records in the UI, `#[egui_probe(order(c, a, b))]` on a type lists fields in order.
+//! - `#[egui_probe(property(name = "scale", get = Self::scale, set = Self::set_scale))]` on a type: Add a record edited through getter and setter.
+//! - `#[egui_probe(button("Respawn", Self::respawn))]` on a type: Add a button record that calls a method when clicked.
+//! - `#[egui_probe(summary = "{name} ({hp} hp)")]` on a struct: Show formatted field values instead of the type name, `summary_with = Self::summary` calls a function.
+//! - `#[egui_probe(item_label = name)]` on a struct: Label items of collections with the field, e.g. `[3] Goblin`.
+//! - `#[egui_probe(inline)]` on a struct: Show all fields in a single row with small labels, like `Vec2` and `Rect`.
+//!   On a variant or an enum: Show fields of variants next to the variant selector.
+//! - `#[egui_probe(open)]`, `#[egui_probe(collapsed)]`, `#[egui_probe(no_header)]`: Show the record initially open, collapsed or always open, also on types.
+//! - `#[egui_probe(tags radio)]` on an enum: Select variants with `inlined`, `combobox`, `radio`, `segmented` or `searchable` widgets.
+//! - `#[egui_probe(hidden)]`, `#[egui_probe(disabled)]` on a variant: Omit or grey out the variant in the selector.
+//! - `#[egui_probe(construct = expr)]` on a variant, `#[egui_probe(default = expr)]` on its fields: Values used when the variant is selected.
+//! - `#[egui_probe(remember_variants)]` on an enum: Restore last-edited state of a variant when it is selected again.
+//! - `#[egui_probe(category = "Lights")]` on a variant: Show variants of the category in a submenu.
+//! - `#[egui_probe(constrain = Self::check)]` on a type: Check invariants after any field changes, errors are shown on the header row.
 //!
 //! ## License
 //!
@@ -96,28 +122,44 @@
 #![allow(clippy::inline_always, clippy::use_self)]
 
 mod algebra;
+mod angle;
 mod array;
 mod boolean;
 mod collections;
 mod color;
+#[cfg(feature = "derive")]
+mod condition;
+mod expression;
+#[cfg(feature = "derive")]
+mod flatten;
+mod float;
+mod group;
 #[cfg(feature = "hashbrown")]
 mod hashbrown;
 mod map;
 mod num;
+mod on_change;
 mod option;
 mod set;
 #[cfg(any(feature = "smallvec1", feature = "smallvec2"))]
 mod small_vec;
 mod text;
 mod ui;
+#[cfg(feature = "derive")]
+mod variants;
 mod vec;
 mod widget;
 
 pub use egui;
 
+use egui::emath::Numeric;
+
 pub use self::{
+    angle::{AngleUnit, EguiProbeAngle},
     boolean::toggle_switch,
     collections::DeleteMe,
+    float::EguiProbeFloat,
+    num::{NumberFormat, Radix},
     option::option_probe_with,
     widget::{Probe, ProbeLayout},
 };
@@ -135,10 +177,23 @@ impl Default for BooleanStyle {
     }
 }
 
+/// Controls how enum variants are selected.
 #[derive(Clone, Copy, Debug)]
 pub enum VariantsStyle {
+    /// Variants are shown in a row of selectable labels.
     Inlined,
+
+    /// Variants are shown in a combobox.
     ComboBox,
+
+    /// Variants are shown in a row of radio buttons.
+    Radio,
+
+    /// Variants are shown as a strip of adjacent toggle buttons.
+    Segmented,
+
+    /// Variants are shown in a combobox with a search field to filter them by name.
+    Searchable,
 }
 
 impl Default for VariantsStyle {
@@ -148,6 +203,26 @@ impl Default for VariantsStyle {
     }
 }
 
+/// Controls how record with inner records is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderMode {
+    /// Record is collapsed until opened by the user.
+    Collapsed,
+
+    /// Record is open until collapsed by the user.
+    Open,
+
+    /// Record has no collapse button and its inner records are always shown.
+    NoHeader,
+}
+
+impl Default for HeaderMode {
+    #[inline]
+    fn default() -> Self {
+        Self::Collapsed
+    }
+}
+
 /// Controls the style of probbing UI.
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -156,6 +231,10 @@ pub struct Style {
     pub field_indent_size: Option<f32>,
     pub add_button_char: Option<char>,
     pub remove_button_char: Option<char>,
+
+    /// If `true`, dragging label of a numeric field horizontally changes the value.
+    /// Hold `Shift` for fine steps and `Ctrl` (`Cmd` on macOS) for coarse steps.
+    pub scrub_labels: bool,
 }
 
 impl Default for Style {
@@ -167,6 +246,7 @@ impl Default for Style {
             field_indent_size: None,
             add_button_char: None,
             remove_button_char: None,
+            scrub_labels: false,
         }
     }
 }
@@ -192,6 +272,11 @@ pub trait EguiProbe {
     ///
     /// It should add pairs of widgets to the UI for each record.
     /// If record has sub-records it should flatten them.
+    ///
+    /// Records are identified by their position.
+    /// If records may be hidden or reordered, emit each of them
+    /// into a ui with its own id, e.g. using [`egui::Ui::push_id`],
+    /// so that their state is kept.
     #[inline(always)]
     fn iterate_inner(
         &mut self,
@@ -200,6 +285,48 @@ pub trait EguiProbe {
     ) {
         let _ = (ui, f);
     }
+
+    /// Returns `true` if value can be changed by dragging its label.
+    ///
+    /// See [`Style::scrub_labels`].
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        false
+    }
+
+    /// Changes the value as if its label was dragged horizontally by `delta` points.
+    ///
+    /// Leaves in `delta` the part that was not applied,
+    /// e.g. because of integer rounding, to be accumulated for the next call.
+    /// Returns `true` if the value changed.
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        let _ = delta;
+        false
+    }
+
+    /// Checks invariants of the value after it or any of its inner values changed.
+    ///
+    /// It may fix up the value or return an error message
+    /// that is shown on the value's header row.
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Returns label that identifies the value among items of a collection.
+    ///
+    /// Collections show it next to the item's index, e.g. `[3] Goblin`.
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns how the record of this value is shown if it has inner records.
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        HeaderMode::Collapsed
+    }
 }
 
 impl<P> EguiProbe for &mut P
@@ -219,6 +346,31 @@ where
     ) {
         P::iterate_inner(*self, ui, f);
     }
+
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        P::can_scrub(&**self)
+    }
+
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        P::scrub(*self, delta)
+    }
+
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        P::constrain(*self)
+    }
+
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        P::item_label(&**self)
+    }
+
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        P::header_mode(&**self)
+    }
 }
 
 impl<P> EguiProbe for Box<P>
@@ -238,6 +390,31 @@ where
     ) {
         P::iterate_inner(&mut *self, ui, f);
     }
+
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        P::can_scrub(&**self)
+    }
+
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        P::scrub(&mut *self, delta)
+    }
+
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        P::constrain(&mut *self)
+    }
+
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        P::item_label(&**self)
+    }
+
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        P::header_mode(&**self)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -260,23 +437,77 @@ pub const fn probe_fn<F>(f: F) -> EguiProbeFn<F> {
     EguiProbeFn(f)
 }
 
+/// Shows `f32` value in radians as an angle in degrees.
+///
+/// Use [`EguiProbeAngle::new`] for `f64` and optional values.
+#[inline(always)]
+pub const fn angle(value: &mut f32) -> EguiProbeAngle<'_, f32> {
+    EguiProbeAngle::new(value)
+}
+
+/// Shows float that must be finite.
+/// NaN and infinite values are shown as an error with a button to reset them.
+#[inline(always)]
+pub const fn finite<'a, T>(value: &'a mut T) -> EguiProbeFloat<'a, T>
+where
+    EguiProbeFloat<'a, T>: EguiProbe,
+{
+    EguiProbeFloat {
+        value,
+        infinite: false,
+    }
+}
+
+/// Shows float that may be set to positive or negative infinity, e.g. for "unlimited" values.
+#[inline(always)]
+pub const fn infinite<'a, T>(value: &'a mut T) -> EguiProbeFloat<'a, T>
+where
+    EguiProbeFloat<'a, T>: EguiProbe,
+{
+    EguiProbeFloat {
+        value,
+        infinite: true,
+    }
+}
+
+/// Shows fraction as percents, e.g. `0.42` as "42 %".
 #[inline(always)]
-pub fn angle(value: &mut f32) -> impl EguiProbe + '_ {
-    probe_fn(move |ui: &mut egui::Ui, _style: &Style| ui.drag_angle(value))
+pub fn percent<N: Numeric>(value: &mut N) -> impl EguiProbe + '_ {
+    probe_fn(move |ui: &mut egui::Ui, _style: &Style| ui.add(num::PERCENT.drag_value(value)))
+}
+
+/// Shows number of bytes in binary units, e.g. `1572864` as "1.5 MiB".
+#[inline(always)]
+pub fn bytes<N: Numeric>(value: &mut N) -> impl EguiProbe + '_ {
+    probe_fn(move |ui: &mut egui::Ui, _style: &Style| ui.add(num::BYTES.drag_value(value)))
+}
+
+/// Shows number of milliseconds as duration, e.g. `250` as "250 ms".
+#[inline(always)]
+pub fn millis<N: Numeric>(value: &mut N) -> impl EguiProbe + '_ {
+    probe_fn(move |ui: &mut egui::Ui, _style: &Style| ui.add(num::MILLIS.drag_value(value)))
+}
+
+/// Shows number of seconds as duration, e.g. `0.25` as "250 ms".
+#[inline(always)]
+pub fn seconds<N: Numeric>(value: &mut N) -> impl EguiProbe + '_ {
+    probe_fn(move |ui: &mut egui::Ui, _style: &Style| ui.add(num::SECONDS.drag_value(value)))
 }
 
 pub mod customize {
     use std::ops::RangeFull;
 
     use super::{
-        EguiProbe, Style,
+        EguiProbe, HeaderMode, Style,
         boolean::ToggleSwitch,
         collections::EguiProbeFrozen,
         color::{
             EguiProbeRgb, EguiProbeRgba, EguiProbeRgbaPremultiplied, EguiProbeRgbaUnmultiplied,
         },
         egui,
-        num::{EguiProbeRange, StepUnset},
+        group::{EguiProbeButton, EguiProbeGroup, EguiProbeHeader, EguiProbeTabs},
+        num::{EguiProbeRange, NumberFormat, StepUnset},
+        on_change::EguiProbeChanged,
         probe_fn,
         text::EguiProbeMultiline,
     };
@@ -307,6 +538,7 @@ pub mod customize {
             value,
             range,
             step: StepUnset,
+            format: NumberFormat::new(),
         }
     }
 
@@ -319,7 +551,12 @@ pub mod customize {
     where
         EguiProbeRange<'a, T, R, S>: EguiProbe,
     {
-        EguiProbeRange { value, range, step }
+        EguiProbeRange {
+            value,
+            range,
+            step,
+            format: NumberFormat::new(),
+        }
     }
 
     #[inline(always)]
@@ -334,6 +571,7 @@ pub mod customize {
             value,
             range: ..,
             step,
+            format: NumberFormat::new(),
         }
     }
 
@@ -392,6 +630,37 @@ pub mod customize {
     {
         EguiProbeRgbaUnmultiplied { value }
     }
+
+    #[inline(always)]
+    pub const fn probe_group<F>(f: F) -> EguiProbeGroup<F>
+    where
+        F: FnMut(&mut egui::Ui, &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe)),
+    {
+        EguiProbeGroup(f)
+    }
+
+    #[inline(always)]
+    pub const fn probe_changed<'a>(
+        value: &'a mut dyn EguiProbe,
+        changed: &'a mut bool,
+    ) -> EguiProbeChanged<'a> {
+        EguiProbeChanged { value, changed }
+    }
+
+    #[inline(always)]
+    pub const fn probe_tabs<'a>(tabs: &'a [&'a str], selected: &'a mut usize) -> EguiProbeTabs<'a> {
+        EguiProbeTabs { tabs, selected }
+    }
+
+    #[inline(always)]
+    pub const fn probe_button<'a>(label: &'a str, clicked: &'a mut bool) -> EguiProbeButton<'a> {
+        EguiProbeButton { label, clicked }
+    }
+
+    #[inline(always)]
+    pub const fn probe_header(mode: HeaderMode, value: &mut dyn EguiProbe) -> EguiProbeHeader<'_> {
+        EguiProbeHeader { value, mode }
+    }
 }
 
 #[cfg(feature = "derive")]
@@ -403,8 +672,16 @@ extern crate self as egui_probe;
 #[cfg(feature = "derive")]
 #[doc(hidden)]
 pub mod private {
+    pub use super::condition::{ConditionField, ViaCopy, ViaRef};
     pub use super::customize::*;
+    pub use super::flatten::flatten_records;
+    pub use super::on_change::OnChange;
+    pub use super::variants::{
+        MaybeDefault, VariantSelector, ViaDefault, ViaNoDefault, variants_selector,
+    };
     pub use core::stringify;
+    pub use std::format;
+    pub use std::string::{String, ToString};
 }
 
 #[cfg(feature = "derive")]
@@ -422,6 +699,245 @@ fn test_all_attributes() {
 
     struct NoProbe;
 
+    #[derive(EguiProbe)]
+    #[egui_probe(constrain = Self::constrain_weights)]
+    struct Constrained {
+        min: f32,
+        max: f32,
+        weights: [f32; 3],
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(tabs)]
+    struct Tabs {
+        name: String,
+
+        #[egui_probe(group = "Physics")]
+        mass: f32,
+
+        #[egui_probe(group = "Render")]
+        visible: bool,
+
+        #[egui_probe(group = "Physics")]
+        friction: f32,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(on_change = Self::changed)]
+    struct OnChange {
+        #[egui_probe(on_change = Self::rebuild_mesh)]
+        subdivisions: u32,
+
+        #[egui_probe(on_change = Self::normalize, group = "Direction")]
+        direction: [f32; 3],
+
+        #[egui_probe(skip)]
+        mesh: Vec<f32>,
+    }
+
+    impl OnChange {
+        fn changed(&mut self) {}
+
+        fn rebuild_mesh(&mut self) {
+            self.mesh = vec![0.0; self.subdivisions as usize];
+        }
+
+        fn normalize(direction: &mut [f32; 3]) {
+            let len = direction.iter().map(|v| v * v).sum::<f32>().sqrt();
+            if len > 0.0 {
+                direction.iter_mut().for_each(|v| *v /= len);
+            }
+        }
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(on_change = |_: &mut Self| {})]
+    enum OnChangeEnum {
+        A,
+        B {
+            #[egui_probe(on_change = |len: &mut usize| *len = (*len).min(10))]
+            len: usize,
+        },
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(order(c, a))]
+    struct Ordered {
+        a: u8,
+        b: u8,
+        c: u8,
+
+        #[egui_probe(order = -1)]
+        d: u8,
+
+        #[egui_probe(order = 1)]
+        e: u8,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(order(1, 0))]
+    struct OrderedTuple(u8, u16);
+
+    #[derive(EguiProbe)]
+    #[egui_probe(property(name = "uniform scale", get = Self::scale_uniform, set = Self::set_scale_uniform))]
+    #[egui_probe(on_change = |_: &mut Self| {})]
+    struct Properties {
+        scale: [f32; 3],
+    }
+
+    impl Properties {
+        fn scale_uniform(&self) -> f32 {
+            self.scale.iter().sum::<f32>() / 3.0
+        }
+
+        fn set_scale_uniform(&mut self, scale: f32) {
+            self.scale = [scale; 3];
+        }
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(button("Respawn", Self::respawn))]
+    #[egui_probe(button("Reset", |b: &mut Self| b.hp = 100))]
+    #[egui_probe(on_change = |_: &mut Self| {})]
+    struct Buttons {
+        hp: u32,
+    }
+
+    impl Buttons {
+        fn respawn(&mut self) {
+            self.hp = 100;
+        }
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(summary = "{name} ({hp:>3} hp) {{{name}}}")]
+    struct Summary {
+        name: String,
+        hp: u32,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(summary = "{1}: {0:.2}")]
+    struct SummaryTuple(f32, &'static str);
+
+    #[derive(EguiProbe)]
+    #[egui_probe(summary_with = |s: &Self| format!("{} items", s.items.len()))]
+    struct SummaryWith {
+        items: Vec<u8>,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(inline, on_change = |_: &mut Self| {})]
+    struct Color {
+        #[egui_probe(on_change = |_: &mut u8| {})]
+        r: u8,
+        #[egui_probe(range = 0..=r)]
+        g: u8,
+        #[egui_probe(name = "blue", visible_if = r > 0)]
+        b: u8,
+        #[egui_probe(skip)]
+        a: u8,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(inline)]
+    struct Extent(f32, f32);
+
+    #[derive(EguiProbe)]
+    #[egui_probe(inline, constrain = Self::check)]
+    struct Span {
+        start: f32,
+        end: f32,
+    }
+
+    impl Span {
+        fn check(&mut self) -> Result<(), String> {
+            if self.end < self.start {
+                self.end = self.start;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(open)]
+    struct Headers {
+        #[egui_probe(collapsed)]
+        color: Color,
+        #[egui_probe(no_header)]
+        monsters: Vec<Monster>,
+        #[egui_probe(open, range = 0..=10)]
+        count: Option<u8>,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(no_header)]
+    enum HeadersEnum {
+        A { a: u8 },
+    }
+
+    #[derive(EguiProbe)]
+    enum Size {
+        Auto,
+
+        #[egui_probe(inline)]
+        Fixed {
+            #[egui_probe(on_change = |_: &mut Size| {})]
+            w: f32,
+            #[egui_probe(name = "height", range = 0.0..=w)]
+            h: f32,
+        },
+
+        Relative {
+            fraction: f32,
+        },
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(inline, tags segmented)]
+    enum Padding {
+        Uniform(f32),
+        Symmetric { x: f32, y: f32 },
+    }
+
+    #[derive(Default, EguiProbe)]
+    #[egui_probe(item_label = name)]
+    struct Monster {
+        name: String,
+        hp: u32,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(item_label = 1)]
+    struct Labelled(u8, &'static str);
+
+    #[derive(EguiProbe)]
+    struct Monsters {
+        monsters: Vec<Monster>,
+        labelled: [Labelled; 2],
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(property(name = "is a", get = |e: &Self| matches!(e, Self::A), set = |e: &mut Self, a: bool| if a { *e = Self::A }))]
+    enum PropertiesEnum {
+        A,
+        B,
+    }
+
+    impl Constrained {
+        fn constrain_weights(&mut self) -> Result<(), &'static str> {
+            if self.max < self.min {
+                self.max = self.min;
+            }
+
+            let sum: f32 = self.weights.iter().sum();
+            if (sum - 1.0).abs() > 0.001 {
+                return Err("Weights must sum to 1");
+            }
+            Ok(())
+        }
+    }
+
     #[derive(EguiProbe)]
     #[egui_probe(rename_all = Train-Case)]
     struct FieldAttributes {
@@ -437,6 +953,12 @@ fn test_all_attributes() {
         #[egui_probe(as angle)]
         c: f32,
 
+        #[egui_probe(angle(degrees, range = -180..=180, wrap))]
+        c1: f64,
+
+        #[egui_probe(angle)]
+        c2: Option<f32>,
+
         #[egui_probe(range = 0..=100)]
         d: u8,
 
@@ -466,6 +988,182 @@ fn test_all_attributes() {
 
         #[egui_probe(rgba_unmultiplied)]
         m: [f32; 4],
+
+        #[egui_probe(decimals = 2, suffix = " m/s")]
+        n: f32,
+
+        #[egui_probe(range = 0..=0xFF, hex, prefix = "mask ")]
+        o: u32,
+
+        #[egui_probe(binary)]
+        p: Option<u8>,
+
+        #[egui_probe(range = 0..=0o7777 by 8, octal)]
+        q: u16,
+
+        #[egui_probe(range = 0.0..=1.0, expression)]
+        q1: Option<f64>,
+
+        #[egui_probe(expression, decimals = 3)]
+        q2: f32,
+
+        #[egui_probe(percent)]
+        r: f32,
+
+        #[egui_probe(bytes)]
+        s: u64,
+
+        #[egui_probe(millis)]
+        t: u32,
+
+        #[egui_probe(seconds)]
+        u: f64,
+
+        #[egui_probe(finite)]
+        v: f32,
+
+        #[egui_probe(infinite)]
+        w: Option<f64>,
+
+        min_speed: f32,
+
+        #[egui_probe(range = min_speed..)]
+        max_speed: f32,
+
+        #[egui_probe(range = min_speed..=max_speed by (max_speed - min_speed) / 100.0)]
+        speed: f32,
+
+        #[egui_probe(flatten)]
+        x: TypeAttributes<u8>,
+
+        #[egui_probe(flatten(prefix))]
+        y: Constrained,
+
+        #[egui_probe(flatten(prefix = "Z "))]
+        z: TypeAttributes<f32>,
+
+        #[egui_probe(group = "Physics")]
+        mass: f32,
+
+        #[egui_probe(group = "Render")]
+        visible: bool,
+
+        #[egui_probe(group = "Physics", range = 0.0..=mass)]
+        friction: f32,
+
+        shadows_enabled: bool,
+
+        #[egui_probe(visible_if = shadows_enabled)]
+        shadow_softness: f32,
+
+        #[egui_probe(enabled_if = shadows_enabled && shadow_softness > 0.0)]
+        shadow_color: egui::Color32,
+    }
+
+    fn min(a: f32, b: f32) -> f32 {
+        a.min(b)
+    }
+
+    #[derive(EguiProbe)]
+    struct Conditions {
+        name: String,
+        mode: Effect,
+        min: f32,
+        max: f32,
+
+        #[egui_probe(visible_if = !name.is_empty() && matches!(mode, Effect::None))]
+        details: String,
+
+        #[egui_probe(enabled_if = min(max, 1.0) > 0.0, range = min..=max)]
+        value: f32,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(tags radio)]
+    enum RadioTags {
+        A,
+        B(u8),
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(tags segmented)]
+    enum SegmentedTags {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(tags searchable)]
+    enum SearchableTags {
+        A,
+        B {
+            b: u8,
+        },
+
+        #[egui_probe(hidden)]
+        Internal,
+
+        #[egui_probe(disabled, name = "deprecated")]
+        Deprecated(u8),
+    }
+
+    #[derive(EguiProbe)]
+    struct NoDefault(u8);
+
+    #[derive(EguiProbe)]
+    enum Construct {
+        #[egui_probe(construct = Self::A(NoDefault(1)))]
+        A(NoDefault),
+
+        B {
+            #[egui_probe(default = NoDefault(2))]
+            nd: NoDefault,
+            n: u8,
+        },
+
+        NotSelectable(NoDefault),
+    }
+
+    #[derive(Clone, EguiProbe)]
+    #[egui_probe(tags searchable, remember_variants)]
+    enum Effect {
+        None,
+
+        #[egui_probe(category = "Lights")]
+        PointLight {
+            intensity: f32,
+        },
+
+        #[egui_probe(category = "Particles")]
+        Sparks {
+            count: u32,
+        },
+
+        #[egui_probe(category = "Lights")]
+        SpotLight {
+            intensity: f32,
+            angle: f32,
+        },
+
+        #[egui_probe(category = "Particles", hidden)]
+        Smoke,
+    }
+
+    #[derive(Clone, EguiProbe)]
+    #[egui_probe(remember_variants)]
+    enum Shape {
+        Circle { radius: f32, color: egui::Color32 },
+        Square { size: f32, color: egui::Color32 },
+        Line { size: u32 },
+        Point,
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(where T: EguiProbe)]
+    enum ConstructGeneric<T> {
+        A(T),
+        B,
     }
 
     #[derive(EguiProbe)]
@@ -481,5 +1179,12 @@ fn test_all_attributes() {
 
             b: f32,
         },
+
+        C {
+            len: usize,
+
+            #[egui_probe(range = 0..=len)]
+            index: usize,
+        },
     }
 }
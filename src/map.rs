@@ -143,7 +143,8 @@ where
                 value,
                 delete: false,
             };
-            f(&key.to_string(), ui, &mut item);
+            let label = key.to_string();
+            ui.push_id(&label, |ui| f(&label, ui, &mut item));
             !item.delete
         });
     }
@@ -2,16 +2,550 @@ use core::ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive};
 
 use egui::emath::Numeric;
 
-use crate::{EguiProbe, Style, option::option_probe_with};
+use crate::{EguiProbe, Style, expression, option::option_probe_with};
 
 /// Marker type to indicate that the step for range is not set.
 pub struct StepUnset;
 
+/// Radix used to show and parse integer values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    Binary,
+    Octal,
+    Hexadecimal,
+}
+
+impl Radix {
+    const fn base(self) -> u32 {
+        match self {
+            Radix::Decimal => 10,
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Hexadecimal => 16,
+        }
+    }
+
+    const fn marker(self) -> &'static str {
+        match self {
+            Radix::Decimal => "",
+            Radix::Binary => "0b",
+            Radix::Octal => "0o",
+            Radix::Hexadecimal => "0x",
+        }
+    }
+}
+
+/// Controls how numeric value is shown and how typed text is parsed back.
+#[derive(Clone, Copy, Debug)]
+pub struct NumberFormat {
+    /// Fixed number of decimals for floating point values.
+    /// Ignored for integer values.
+    pub decimals: Option<usize>,
+
+    /// Text shown before the value.
+    pub prefix: &'static str,
+
+    /// Text shown after the value, e.g. a unit.
+    pub suffix: &'static str,
+
+    /// Radix for integer values.
+    /// Ignored for floating point values.
+    pub radix: Radix,
+
+    /// If `true`, value is edited as text that may contain arithmetic expression,
+    /// e.g. `2*pi`, `1/60` or `+=5`.
+    pub expression: bool,
+}
+
+impl Default for NumberFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Returns format that shows value as is.
+    #[must_use]
+    pub const fn new() -> Self {
+        NumberFormat {
+            decimals: None,
+            prefix: "",
+            suffix: "",
+            radix: Radix::Decimal,
+            expression: false,
+        }
+    }
+
+    /// Shows widget to edit the value within the range.
+    pub fn show<N: Numeric>(
+        self,
+        value: &mut N,
+        range: RangeInclusive<N>,
+        speed: Option<f64>,
+        ui: &mut egui::Ui,
+    ) -> egui::Response {
+        if !value.to_f64().is_finite() {
+            let (min, max) = (range.start().to_f64(), range.end().to_f64());
+            let reset = N::from_f64(clamp_to_range(0.0, min, max));
+            return non_finite_badge(value, reset, ui.visuals().warn_fg_color, ui);
+        }
+
+        if self.expression {
+            return expression_edit(value, range, self, ui);
+        }
+
+        let mut drag = self.drag_value(value).range(range);
+        if let Some(speed) = speed {
+            drag = drag.speed(speed);
+        }
+        ui.add(drag)
+    }
+
+    /// Returns radix used for values of type `N`.
+    const fn radix_for<N: Numeric>(self) -> Radix {
+        if N::INTEGRAL {
+            self.radix
+        } else {
+            Radix::Decimal
+        }
+    }
+
+    /// Formats integer value in non-decimal radix.
+    fn format_radix(n: f64, radix: Radix) -> String {
+        let sign = if n < 0.0 { "-" } else { "" };
+        let digits = match radix {
+            Radix::Decimal => format!("{}", n.abs() as u64),
+            Radix::Binary => format!("{:b}", n.abs() as u64),
+            Radix::Octal => format!("{:o}", n.abs() as u64),
+            Radix::Hexadecimal => format!("{:X}", n.abs() as u64),
+        };
+        format!("{sign}{}{digits}", radix.marker())
+    }
+
+    /// Formats value of type `N` with prefix and suffix.
+    fn format<N: Numeric>(self, n: f64) -> String {
+        let radix = self.radix_for::<N>();
+
+        let number = if radix != Radix::Decimal {
+            Self::format_radix(n, radix)
+        } else if N::INTEGRAL {
+            format!("{n}")
+        } else if let Some(decimals) = self.decimals {
+            format!("{n:.decimals$}")
+        } else {
+            egui::emath::format_with_decimals_in_range(n, 0..=6)
+        };
+
+        format!("{}{number}{}", self.prefix, self.suffix)
+    }
+
+    /// Removes prefix and suffix from typed text.
+    fn strip(self, text: &str) -> &str {
+        let mut text = text.trim();
+        text = text.strip_prefix(self.prefix.trim()).unwrap_or(text).trim();
+        text.strip_suffix(self.suffix.trim()).unwrap_or(text).trim()
+    }
+
+    /// Returns `DragValue` for the value with this format applied.
+    pub fn drag_value<N: Numeric>(self, value: &mut N) -> egui::DragValue<'_> {
+        let mut drag = egui::DragValue::new(value)
+            .prefix(self.prefix)
+            .suffix(self.suffix);
+
+        if !N::INTEGRAL
+            && let Some(decimals) = self.decimals
+        {
+            drag = drag.fixed_decimals(decimals);
+        }
+
+        let radix = self.radix_for::<N>();
+
+        if radix != Radix::Decimal {
+            drag = drag.custom_formatter(move |n, _| Self::format_radix(n, radix));
+        }
+
+        if radix != Radix::Decimal || !self.prefix.is_empty() || !self.suffix.is_empty() {
+            drag = drag.custom_parser(move |text| self.parse(text, radix));
+        }
+
+        drag
+    }
+
+    /// Parses text typed into the value field.
+    ///
+    /// Accepts text with or without prefix, suffix and radix marker.
+    fn parse(self, text: &str, radix: Radix) -> Option<f64> {
+        let text: String = self
+            .strip(text)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| if c == '\u{2212}' { '-' } else { c })
+            .collect();
+
+        if radix == Radix::Decimal {
+            return text.parse().ok();
+        }
+
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(&text)),
+        };
+
+        let marker = radix.marker();
+        let digits = match digits.get(..marker.len()) {
+            Some(head) if head.eq_ignore_ascii_case(marker) => &digits[marker.len()..],
+            _ => digits,
+        };
+
+        let value = u64::from_str_radix(digits, radix.base()).ok()? as f64;
+        Some(if negative { -value } else { value })
+    }
+}
+
+/// Text of the expression being edited and error of its last evaluation.
+#[derive(Clone)]
+struct ExpressionState {
+    text: String,
+    error: Option<String>,
+
+    /// Whether user has changed the text since the field was focused.
+    edited: bool,
+}
+
+/// Clamps value to the range.
+/// Reversed range is clamped to with swapped bounds, as `DragValue` does.
+fn clamp_to_range(value: f64, min: f64, max: f64) -> f64 {
+    if min > max {
+        value.clamp(max, min)
+    } else {
+        value.clamp(min, max)
+    }
+}
+
+/// Shows text field that evaluates typed arithmetic expression when editing is finished.
+fn expression_edit<N: Numeric>(
+    value: &mut N,
+    range: RangeInclusive<N>,
+    format: NumberFormat,
+    ui: &mut egui::Ui,
+) -> egui::Response {
+    let mut changed = false;
+
+    let mut r = ui
+        .scope(|ui| {
+            let id = ui.next_auto_id();
+            let mut state = ui.data_mut(|d| d.get_temp::<ExpressionState>(id));
+
+            let mut text = match &state {
+                Some(state) => state.text.clone(),
+                None => format.format::<N>(value.to_f64()),
+            };
+
+            let text_edit = egui::TextEdit::singleline(&mut text)
+                .id(id)
+                .text_color_opt(match &state {
+                    Some(ExpressionState { error: Some(_), .. }) => {
+                        Some(ui.visuals().error_fg_color)
+                    }
+                    _ => None,
+                })
+                .desired_width(ui.spacing().interact_size.x * 2.0);
+
+            let r = ui.add(text_edit);
+
+            if r.lost_focus() {
+                let edited = r.changed() || state.as_ref().is_some_and(|state| state.edited);
+
+                if !edited || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                    state = None;
+                } else {
+                    match expression::evaluate(format.strip(&text), value.to_f64()) {
+                        Ok(mut result) => {
+                            if N::INTEGRAL {
+                                result = result.round();
+                            }
+                            let (min, max) = (range.start().to_f64(), range.end().to_f64());
+                            let result = N::from_f64(clamp_to_range(result, min, max));
+                            if result.to_f64() != value.to_f64() {
+                                *value = result;
+                                changed = true;
+                            }
+                            state = None;
+                        }
+                        Err(error) => {
+                            state = Some(ExpressionState {
+                                text,
+                                error: Some(error),
+                                edited: true,
+                            });
+                        }
+                    }
+                }
+            } else if r.changed() {
+                state = Some(ExpressionState {
+                    text,
+                    error: None,
+                    edited: true,
+                });
+            } else if r.has_focus() && state.is_none() {
+                state = Some(ExpressionState {
+                    text,
+                    error: None,
+                    edited: false,
+                });
+            }
+
+            if let Some(ExpressionState {
+                error: Some(error), ..
+            }) = &state
+            {
+                r.on_hover_text(error);
+            }
+
+            ui.data_mut(|d| match state {
+                Some(state) => d.insert_temp(id, state),
+                None => d.remove::<ExpressionState>(id),
+            });
+        })
+        .response;
+
+    if changed {
+        r.mark_changed();
+    }
+
+    r
+}
+
 /// Bundles value and a range to show probbing UI to edit the value in that range.
 pub struct EguiProbeRange<'a, T, R, S = StepUnset> {
     pub value: &'a mut T,
     pub range: R,
     pub step: S,
+    pub format: NumberFormat,
+}
+
+impl<T, R, S> EguiProbeRange<'_, T, R, S> {
+    /// Shows floating point value with fixed number of decimals.
+    /// Ignored for integer values.
+    #[must_use]
+    pub const fn decimals(mut self, decimals: usize) -> Self {
+        self.format.decimals = Some(decimals);
+        self
+    }
+
+    /// Shows text before the value.
+    #[must_use]
+    pub const fn prefix(mut self, prefix: &'static str) -> Self {
+        self.format.prefix = prefix;
+        self
+    }
+
+    /// Shows text after the value.
+    #[must_use]
+    pub const fn suffix(mut self, suffix: &'static str) -> Self {
+        self.format.suffix = suffix;
+        self
+    }
+
+    /// Shows and parses integer value in specified radix.
+    /// Ignored for floating point values.
+    #[must_use]
+    pub const fn radix(mut self, radix: Radix) -> Self {
+        self.format.radix = radix;
+        self
+    }
+
+    /// Shows and parses integer value as hexadecimal.
+    #[must_use]
+    pub const fn hexadecimal(self) -> Self {
+        self.radix(Radix::Hexadecimal)
+    }
+
+    /// Shows and parses integer value as binary.
+    #[must_use]
+    pub const fn binary(self) -> Self {
+        self.radix(Radix::Binary)
+    }
+
+    /// Shows and parses integer value as octal.
+    #[must_use]
+    pub const fn octal(self) -> Self {
+        self.radix(Radix::Octal)
+    }
+
+    /// Edits value as text that may contain arithmetic expression.
+    #[must_use]
+    pub const fn expression(mut self) -> Self {
+        self.format.expression = true;
+        self
+    }
+}
+
+/// Set of units to show and parse scaled values, e.g. byte sizes and durations.
+pub(crate) struct Units {
+    /// Units from smallest to largest with their scale relative to the value.
+    pub shown: &'static [(&'static str, f64)],
+
+    /// Additional unit names accepted when parsing.
+    pub aliases: &'static [(&'static str, f64)],
+
+    /// Index of the unit used for zero and for numbers typed without unit.
+    pub default: usize,
+}
+
+pub(crate) static PERCENT: Units = Units {
+    shown: &[("%", 0.01)],
+    aliases: &[],
+    default: 0,
+};
+
+pub(crate) static BYTES: Units = Units {
+    shown: &[
+        ("B", 1.0),
+        ("KiB", 1024.0),
+        ("MiB", 1_048_576.0),
+        ("GiB", 1_073_741_824.0),
+        ("TiB", 1_099_511_627_776.0),
+    ],
+    aliases: &[
+        ("bytes", 1.0),
+        ("kb", 1e3),
+        ("mb", 1e6),
+        ("gb", 1e9),
+        ("tb", 1e12),
+    ],
+    default: 0,
+};
+
+pub(crate) static MILLIS: Units = Units {
+    shown: &[
+        ("ms", 1.0),
+        ("s", 1_000.0),
+        ("min", 60_000.0),
+        ("h", 3_600_000.0),
+    ],
+    aliases: &[("sec", 1_000.0), ("m", 60_000.0)],
+    default: 0,
+};
+
+pub(crate) static SECONDS: Units = Units {
+    shown: &[("ms", 0.001), ("s", 1.0), ("min", 60.0), ("h", 3_600.0)],
+    aliases: &[("sec", 1.0), ("m", 60.0)],
+    default: 1,
+};
+
+impl Units {
+    /// Returns `DragValue` that shows the value in the largest fitting unit.
+    pub fn drag_value<'a, N: Numeric>(&'static self, value: &'a mut N) -> egui::DragValue<'a> {
+        let current = value.to_f64();
+        let (_, scale) = self.unit_for(current);
+
+        // Text that was shown and not edited keeps the value as is,
+        // even if it is not exact.
+        egui::DragValue::new(value)
+            .speed(scale * 0.1)
+            .custom_formatter(move |n, _| self.format::<N>(n))
+            .custom_parser(move |text| {
+                if text == self.format::<N>(current) {
+                    Some(current)
+                } else {
+                    self.parse(text)
+                }
+            })
+    }
+
+    fn unit_for(&self, value: f64) -> (&'static str, f64) {
+        self.shown
+            .iter()
+            .rev()
+            .find(|(_, scale)| value.abs() >= *scale)
+            .copied()
+            .unwrap_or(self.shown[self.default])
+    }
+
+    /// Formats value of type `N` in the largest fitting unit.
+    ///
+    /// Uses at most two decimals if the text parses back to the same value,
+    /// otherwise a smaller unit or as many decimals as needed,
+    /// so that `DragValue` does not change the value when it parses the shown text.
+    fn format<N: Numeric>(&self, value: f64) -> String {
+        let round_trips = |text: &String| {
+            self.parse(text)
+                .is_some_and(|parsed| N::from_f64(parsed) == N::from_f64(value))
+        };
+
+        let (name, scale) = self.unit_for(value);
+        let largest = self.shown.iter().position(|(n, _)| *n == name).unwrap_or(0);
+
+        let mut exact = None;
+        for &(name, scale) in self.shown[..=largest].iter().rev() {
+            let number = unscale(value, scale);
+            let short = format!("{number:.2}");
+            let short = short.trim_end_matches('0').trim_end_matches('.');
+
+            let text = format!("{short} {name}");
+            if round_trips(&text) {
+                return text;
+            }
+
+            let text = format!("{number} {name}");
+            if exact.is_none() && round_trips(&text) {
+                exact = Some(text);
+            }
+        }
+
+        exact.unwrap_or_else(|| format!("{} {name}", unscale(value, scale)))
+    }
+
+    fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| if c == '\u{2212}' { '-' } else { c })
+            .collect();
+
+        let split = text
+            .rfind(|c: char| !(c.is_alphabetic() || c == '%'))
+            .map_or(0, |idx| idx + 1);
+        let (number, unit) = text.split_at(split);
+        let number: f64 = number.parse().ok()?;
+
+        let scale = if unit.is_empty() {
+            self.shown[self.default].1
+        } else {
+            self.shown
+                .iter()
+                .chain(self.aliases)
+                .find(|(name, _)| name.eq_ignore_ascii_case(unit))?
+                .1
+        };
+
+        Some(rescale(number, scale))
+    }
+}
+
+/// Converts value to number of units of the `scale`.
+///
+/// Divides by inverse of small scales, e.g. `0.01` or `0.001`,
+/// as it is exact, unlike the scale itself.
+fn unscale(value: f64, scale: f64) -> f64 {
+    if scale < 1.0 {
+        value * scale.recip()
+    } else {
+        value / scale
+    }
+}
+
+/// Converts number of units of the `scale` to value.
+fn rescale(number: f64, scale: f64) -> f64 {
+    if scale < 1.0 {
+        number / scale.recip()
+    } else {
+        number * scale
+    }
 }
 
 pub fn non_negative<N: Numeric>(value: &mut N) -> EguiProbeRange<'_, N, RangeFrom<N>> {
@@ -19,6 +553,7 @@ pub fn non_negative<N: Numeric>(value: &mut N) -> EguiProbeRange<'_, N, RangeFro
         value,
         range: N::from_f64(0.0)..,
         step: StepUnset,
+        format: NumberFormat::new(),
     }
 }
 
@@ -54,20 +589,106 @@ pub fn non_negative<N: Numeric>(value: &mut N) -> EguiProbeRange<'_, N, RangeFro
 //     EguiProbeRange { value, range }
 // }
 
+/// Shows badge for NaN or infinite value with a button to reset it to `reset`.
+pub(crate) fn non_finite_badge<N: Numeric>(
+    value: &mut N,
+    reset: N,
+    color: egui::Color32,
+    ui: &mut egui::Ui,
+) -> egui::Response {
+    let v = value.to_f64();
+    let text = if v.is_nan() {
+        "NaN"
+    } else if v > 0.0 {
+        "+∞"
+    } else {
+        "-∞"
+    };
+
+    let mut changed = false;
+    let mut r = ui
+        .horizontal(|ui| {
+            ui.label(egui::RichText::new(text).strong().color(color));
+            if ui
+                .small_button("Reset")
+                .on_hover_text(format!("Reset to {}", reset.to_f64()))
+                .clicked()
+            {
+                *value = reset;
+                changed = true;
+            }
+        })
+        .response;
+
+    if changed {
+        r.mark_changed();
+    }
+
+    r
+}
+
+/// Changes value by `delta` points of label drag.
+/// Leaves in `delta` points that were not applied due to rounding.
+/// Returns `true` if the value changed.
+fn scrub<N: Numeric>(value: &mut N, delta: &mut f64, speed: f64, range: RangeInclusive<N>) -> bool {
+    let (min, max) = (range.start().to_f64(), range.end().to_f64());
+    let before = value.to_f64();
+    let target = before + *delta * speed;
+    let clamped = clamp_to_range(target, min, max);
+
+    *value = if N::INTEGRAL {
+        N::from_f64(clamped.round())
+    } else {
+        N::from_f64(clamped)
+    };
+
+    *delta = if target == clamped && speed > 0.0 {
+        (target - value.to_f64()) / speed
+    } else {
+        0.0
+    };
+
+    value.to_f64() != before
+}
+
 macro_rules! impl_for_num_types {
     ($num_type:ident) => {
         impl EguiProbe for $num_type {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+                if !self.to_f64().is_finite() {
+                    let color = ui.visuals().warn_fg_color;
+                    return non_finite_badge(self, $num_type::default(), color, ui);
+                }
                 ui.add(egui::DragValue::new(self))
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self, delta, 1.0, $num_type::MIN..=$num_type::MAX)
+            }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeFull> {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
-                ui.add(egui::DragValue::new(self.value).range(range))
+                self.format.show(self.value, range, None, ui)
+            }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, 1.0, $num_type::MIN..=$num_type::MAX)
             }
         }
 
@@ -77,7 +698,7 @@ macro_rules! impl_for_num_types {
                 let range = self.range.start..=$num_type::MAX;
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range)).changed();
+                    changed |= self.format.show(self.value, range, None, ui).changed();
                     ui.weak(format!("{}..", self.range.start));
                 }).response;
 
@@ -87,6 +708,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, 1.0, self.range.start..=$num_type::MAX)
+            }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeToInclusive<$num_type>> {
@@ -95,7 +726,7 @@ macro_rules! impl_for_num_types {
                 let range = $num_type::MIN..=self.range.end;
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range)).changed();
+                    changed |= self.format.show(self.value, range, None, ui).changed();
                     ui.weak(format!("..={}", self.range.end));
                 }).response;
 
@@ -105,6 +736,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, 1.0, $num_type::MIN..=self.range.end)
+            }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeInclusive<$num_type>> {
@@ -113,7 +754,7 @@ macro_rules! impl_for_num_types {
                 let range = self.range.clone();
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range)).changed();
+                    changed |= self.format.show(self.value, range, None, ui).changed();
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
                 }).response;
 
@@ -123,6 +764,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, 1.0, self.range.clone())
+            }
         }
 
         impl EguiProbe for EguiProbeRange<'_, Option<$num_type>, RangeFull> {
@@ -130,7 +781,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).range(range))
+                    self.format.show(value, range, None, ui)
                 })
             }
         }
@@ -140,7 +791,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.start..=$num_type::MAX;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range));
+                    let r = self.format.show(value, range, None, ui);
                     ui.weak(format!("{}..", self.range.start));
                     r
                 })
@@ -152,7 +803,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=self.range.end;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range));
+                    let r = self.format.show(value, range, None, ui);
                     ui.weak(format!("..={}", self.range.end));
                     r
                 })
@@ -164,7 +815,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.clone();
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range));
+                    let r = self.format.show(value, range, None, ui);
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
                     r
                 })
@@ -181,7 +832,17 @@ macro_rules! impl_for_num_types {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
-                ui.add(egui::DragValue::new(self.value).range(range).speed(self.step.into()))
+                self.format.show(self.value, range, Some(self.step.into()), ui)
+            }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, self.step.into(), $num_type::MIN..=$num_type::MAX)
             }
         }
 
@@ -191,7 +852,7 @@ macro_rules! impl_for_num_types {
                 let range = self.range.start..=$num_type::MAX;
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range).speed(self.step.into())).changed();
+                    changed |= self.format.show(self.value, range, Some(self.step.into()), ui).changed();
                     ui.weak(format!("{}..", self.range.start));
                 }).response;
 
@@ -201,6 +862,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, self.step.into(), self.range.start..=$num_type::MAX)
+            }
         }
 
         impl<S> EguiProbe for EguiProbeRange<'_, $num_type, RangeToInclusive<$num_type>, S> where S: Copy + Into<f64> {
@@ -209,7 +880,7 @@ macro_rules! impl_for_num_types {
                 let range = $num_type::MIN..=self.range.end;
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range).speed(self.step.into())).changed();
+                    changed |= self.format.show(self.value, range, Some(self.step.into()), ui).changed();
                     ui.weak(format!("..={}", self.range.end));
                 }).response;
 
@@ -219,6 +890,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, self.step.into(), $num_type::MIN..=self.range.end)
+            }
         }
 
         impl<S> EguiProbe for EguiProbeRange<'_, $num_type, RangeInclusive<$num_type>, S> where S: Copy + Into<f64> {
@@ -227,7 +908,7 @@ macro_rules! impl_for_num_types {
                 let range = self.range.clone();
                 let mut changed = false;
                 let mut r = ui.horizontal(|ui| {
-                    changed |= ui.add(egui::DragValue::new(self.value).range(range).speed(self.step.into())).changed();
+                    changed |= self.format.show(self.value, range, Some(self.step.into()), ui).changed();
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
                 }).response;
 
@@ -237,6 +918,16 @@ macro_rules! impl_for_num_types {
 
                 r
             }
+
+            #[inline(always)]
+            fn can_scrub(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn scrub(&mut self, delta: &mut f64) -> bool {
+                scrub(self.value, delta, self.step.into(), self.range.clone())
+            }
         }
 
         impl<S> EguiProbe for EguiProbeRange<'_, Option<$num_type>, RangeFull, S> where S: Copy + Into<f64> {
@@ -244,7 +935,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).range(range).speed(self.step.into()))
+                    self.format.show(value, range, Some(self.step.into()), ui)
                 })
             }
         }
@@ -254,7 +945,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.start..=$num_type::MAX;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range).speed(self.step.into()));
+                    let r = self.format.show(value, range, Some(self.step.into()), ui);
                     ui.weak(format!("{}..", self.range.start));
                     r
                 })
@@ -266,7 +957,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=self.range.end;
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range).speed(self.step.into()));
+                    let r = self.format.show(value, range, Some(self.step.into()), ui);
                     ui.weak(format!("..={}", self.range.end));
                     r
                 })
@@ -278,7 +969,7 @@ macro_rules! impl_for_num_types {
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.clone();
                 option_probe_with(self.value, ui, style, $num_type::default, |value, ui, _| {
-                    let r = ui.add(egui::DragValue::new(value).range(range).speed(self.step.into()));
+                    let r = self.format.show(value, range, Some(self.step.into()), ui);
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
                     r
                 })
@@ -292,3 +983,150 @@ macro_rules! impl_for_num_types {
 }
 
 impl_for_num_types!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::{BYTES, MILLIS, NumberFormat, PERCENT, Radix, SECONDS, scrub};
+
+    #[test]
+    fn parse_decimal() {
+        let format = NumberFormat::new();
+        assert_eq!(format.parse("42", Radix::Decimal), Some(42.0));
+        assert_eq!(format.parse(" -1.5 ", Radix::Decimal), Some(-1.5));
+        assert_eq!(format.parse("\u{2212}3", Radix::Decimal), Some(-3.0));
+        assert_eq!(format.parse("1 000", Radix::Decimal), Some(1000.0));
+        assert_eq!(format.parse("abc", Radix::Decimal), None);
+        assert_eq!(format.parse("", Radix::Decimal), None);
+    }
+
+    #[test]
+    fn parse_prefix_suffix() {
+        let format = NumberFormat {
+            prefix: "$ ",
+            suffix: " kg",
+            ..NumberFormat::new()
+        };
+        assert_eq!(format.parse("$ 12 kg", Radix::Decimal), Some(12.0));
+        assert_eq!(format.parse("$12", Radix::Decimal), Some(12.0));
+        assert_eq!(format.parse("12kg", Radix::Decimal), Some(12.0));
+        assert_eq!(format.parse("12", Radix::Decimal), Some(12.0));
+    }
+
+    #[test]
+    fn parse_radix() {
+        let format = NumberFormat::new();
+        assert_eq!(format.parse("0xFF", Radix::Hexadecimal), Some(255.0));
+        assert_eq!(format.parse("0Xff", Radix::Hexadecimal), Some(255.0));
+        assert_eq!(format.parse("ff", Radix::Hexadecimal), Some(255.0));
+        assert_eq!(format.parse("-0x10", Radix::Hexadecimal), Some(-16.0));
+        assert_eq!(format.parse("0b1010", Radix::Binary), Some(10.0));
+        assert_eq!(format.parse("+101", Radix::Binary), Some(5.0));
+        assert_eq!(format.parse("0o17", Radix::Octal), Some(15.0));
+        assert_eq!(format.parse("0b102", Radix::Binary), None);
+        assert_eq!(format.parse("0x", Radix::Hexadecimal), None);
+    }
+
+    #[test]
+    fn parse_formatted() {
+        for radix in [
+            Radix::Decimal,
+            Radix::Binary,
+            Radix::Octal,
+            Radix::Hexadecimal,
+        ] {
+            let format = NumberFormat {
+                prefix: "#",
+                suffix: " px",
+                radix,
+                ..NumberFormat::new()
+            };
+            for n in [0.0, 1.0, -7.0, 255.0, 12345.0] {
+                let text = format.format::<i32>(n);
+                assert_eq!(format.parse(&text, radix), Some(n), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn units_parse() {
+        assert_eq!(BYTES.parse("512"), Some(512.0));
+        assert_eq!(BYTES.parse("2 KiB"), Some(2048.0));
+        assert_eq!(BYTES.parse("1.5mib"), Some(1_572_864.0));
+        assert_eq!(BYTES.parse("3 kb"), Some(3000.0));
+        assert_eq!(MILLIS.parse("1e3"), Some(1000.0));
+        assert_eq!(MILLIS.parse("1e3 ms"), Some(1000.0));
+        assert_eq!(MILLIS.parse("2.5e-1 s"), Some(250.0));
+        assert_eq!(MILLIS.parse("\u{2212}2 m"), Some(-120_000.0));
+        assert_eq!(SECONDS.parse("90"), Some(90.0));
+        assert_eq!(SECONDS.parse("250ms"), Some(0.25));
+        assert_eq!(PERCENT.parse("50 %"), Some(0.5));
+        assert_eq!(MILLIS.parse("5 parsecs"), None);
+        assert_eq!(MILLIS.parse("ms"), None);
+        assert_eq!(MILLIS.parse(""), None);
+    }
+
+    #[test]
+    fn units_round_trip() {
+        assert_eq!(BYTES.format::<u64>(0.0), "0 B");
+        assert_eq!(BYTES.format::<u64>(1536.0), "1.5 KiB");
+        assert_eq!(BYTES.format::<u64>(1_500_000.0), "1500000 B");
+        assert_eq!(MILLIS.format::<u64>(90_000.0), "1.5 min");
+        assert_eq!(MILLIS.format::<u64>(1234.0), "1234 ms");
+        assert_eq!(SECONDS.format::<f32>(0.25), "250 ms");
+        assert_eq!(SECONDS.format::<f64>(1.234_567), "1.234567 s");
+        assert_eq!(PERCENT.format::<f32>(0.125), "12.5 %");
+
+        for (units, values) in [
+            (
+                &BYTES,
+                &[0.0, 1.0, 1023.0, 1536.0, 1_500_000.0, 3_145_728.0][..],
+            ),
+            (
+                &MILLIS,
+                &[0.0, 5.0, 1234.0, 1_500.0, 90_000.0, 7_200_001.0][..],
+            ),
+        ] {
+            for &value in values {
+                let text = units.format::<u64>(value);
+                assert_eq!(units.parse(&text), Some(value), "{text}");
+            }
+        }
+
+        for (units, values) in [
+            (&SECONDS, &[0.0, 0.25, 1.0, 1.234, 45.0, 5_400.123][..]),
+            (&PERCENT, &[0.0, 0.5, -0.25, 1.0, 0.123_456, 0.3][..]),
+        ] {
+            for &value in values {
+                let text = units.format::<f64>(value);
+                assert_eq!(units.parse(&text), Some(value), "{text}");
+
+                let value = f64::from(value as f32);
+                let text = units.format::<f32>(value);
+                let parsed = units.parse(&text).map(|parsed| parsed as f32);
+                assert_eq!(parsed, Some(value as f32), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn scrub_reports_movement() {
+        let mut value = 5u8;
+        let mut delta = 0.25;
+        assert!(!scrub(&mut value, &mut delta, 1.0, 0..=10));
+        assert_eq!((value, delta), (5, 0.25));
+
+        delta += 0.5;
+        assert!(scrub(&mut value, &mut delta, 1.0, 0..=10));
+        assert_eq!(value, 6);
+
+        let mut value = 10u8;
+        let mut delta = 3.0;
+        assert!(!scrub(&mut value, &mut delta, 1.0, 0..=10));
+        assert_eq!((value, delta), (10, 0.0));
+
+        let mut value = 0.5f32;
+        let mut delta = 2.0;
+        assert!(scrub(&mut value, &mut delta, 1.0, 1.0..=0.0));
+        assert_eq!(value, 1.0);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{EguiProbe, HeaderMode, Style};
+
+/// Wrapper that raises a flag when the wrapped value is changed via UI.
+pub struct EguiProbeChanged<'a> {
+    pub value: &'a mut dyn EguiProbe,
+    pub changed: &'a mut bool,
+}
+
+impl EguiProbe for EguiProbeChanged<'_> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        let r = self.value.probe(ui, style);
+        *self.changed |= r.changed();
+        r
+    }
+
+    #[inline(always)]
+    fn iterate_inner(
+        &mut self,
+        ui: &mut egui::Ui,
+        f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
+    ) {
+        // Inner records are wrapped too, so that their changes raise the flag.
+        let changed = &mut *self.changed;
+        self.value.iterate_inner(ui, &mut |name, ui, value| {
+            f(name, ui, &mut EguiProbeChanged { value, changed });
+        });
+    }
+
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        self.value.can_scrub()
+    }
+
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        let changed = self.value.scrub(delta);
+        *self.changed |= changed;
+        changed
+    }
+
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        self.value.constrain()
+    }
+
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        self.value.item_label()
+    }
+
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        self.value.header_mode()
+    }
+}
+
+#[cfg(feature = "derive")]
+/// Marker for callbacks that accept changed field.
+pub enum OnChangeField {}
+
+#[cfg(feature = "derive")]
+/// Marker for callbacks that accept whole value that owns changed field.
+pub enum OnChangeValue {}
+
+#[cfg(feature = "derive")]
+/// Callback invoked when a field of value `V` with type `T` is changed.
+///
+/// Implemented for `FnMut(&mut T)` and `FnMut(&mut V)`,
+/// so derive macro can accept both without knowing callback's signature.
+pub trait OnChange<V, T, M> {
+    /// Calls the callback with the changed field.
+    /// Returns `true` if callback accepts whole value and [`OnChange::call_value`] should be used instead.
+    fn call_field(&mut self, field: &mut T) -> bool;
+
+    /// Calls the callback with the whole value.
+    fn call_value(&mut self, value: &mut V);
+}
+
+#[cfg(feature = "derive")]
+impl<F, V, T> OnChange<V, T, OnChangeField> for F
+where
+    F: FnMut(&mut T),
+{
+    #[inline(always)]
+    fn call_field(&mut self, field: &mut T) -> bool {
+        self(field);
+        false
+    }
+
+    #[inline(always)]
+    fn call_value(&mut self, _value: &mut V) {}
+}
+
+#[cfg(feature = "derive")]
+impl<F, V, T> OnChange<V, T, OnChangeValue> for F
+where
+    F: FnMut(&mut V),
+{
+    #[inline(always)]
+    fn call_field(&mut self, _field: &mut T) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn call_value(&mut self, value: &mut V) {
+        self(value);
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{EguiProbe, collections::tests::with_ui};
+
+    #[derive(EguiProbe)]
+    #[egui_probe(on_change = Self::changed)]
+    struct Stats {
+        #[egui_probe(on_change = Self::hp_changed)]
+        hp: f32,
+        speed: f32,
+        #[egui_probe(skip)]
+        log: Vec<&'static str>,
+    }
+
+    impl Stats {
+        fn hp_changed(&mut self) {
+            self.log.push("hp");
+        }
+
+        fn changed(&mut self) {
+            self.log.push("stats");
+        }
+    }
+
+    #[derive(EguiProbe)]
+    #[egui_probe(on_change = Self::changed)]
+    struct Unit {
+        #[egui_probe(on_change = Self::stats_changed)]
+        stats: Stats,
+        #[egui_probe(skip)]
+        log: Vec<&'static str>,
+    }
+
+    impl Unit {
+        fn stats_changed(&mut self) {
+            self.log.push("stats");
+        }
+
+        fn changed(&mut self) {
+            self.log.push("unit");
+        }
+    }
+
+    /// Runs a frame that scrubs records at the `path` as if their labels were dragged.
+    fn scrub_records(ctx: &egui::Context, value: &mut dyn EguiProbe, path: &[&str]) {
+        fn scrub(ui: &mut egui::Ui, value: &mut dyn EguiProbe, path: &[&str]) {
+            value.iterate_inner(ui, &mut |name, ui, value| match path {
+                [last] if *last == name => assert!(value.scrub(&mut 1.0)),
+                [first, rest @ ..] if *first == name => scrub(ui, value, rest),
+                _ => {}
+            });
+        }
+
+        with_ui(ctx, |ui| scrub(ui, value, path));
+    }
+
+    #[test]
+    fn callbacks_run_once_per_change() {
+        let ctx = egui::Context::default();
+        let mut stats = Stats {
+            hp: 10.0,
+            speed: 1.0,
+            log: Vec::new(),
+        };
+
+        scrub_records(&ctx, &mut stats, &["hp"]);
+        assert_eq!(stats.log, ["hp", "stats"]);
+
+        stats.log.clear();
+        scrub_records(&ctx, &mut stats, &["speed"]);
+        assert_eq!(stats.log, ["stats"]);
+
+        stats.log.clear();
+        scrub_records(&ctx, &mut stats, &["missing"]);
+        assert!(stats.log.is_empty());
+    }
+
+    #[test]
+    fn inner_changes_run_outer_callbacks() {
+        let ctx = egui::Context::default();
+        let mut unit = Unit {
+            stats: Stats {
+                hp: 10.0,
+                speed: 1.0,
+                log: Vec::new(),
+            },
+            log: Vec::new(),
+        };
+
+        scrub_records(&ctx, &mut unit, &["stats", "hp"]);
+        assert_eq!(unit.stats.log, ["hp", "stats"]);
+        assert_eq!(unit.log, ["stats", "unit"]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{EguiProbe, Style};
+use crate::{EguiProbe, HeaderMode, Style};
 
 impl<T> EguiProbe for Option<T>
 where
@@ -21,6 +21,31 @@ where
             value.iterate_inner(ui, f);
         }
     }
+
+    #[inline(always)]
+    fn can_scrub(&self) -> bool {
+        self.as_ref().is_some_and(T::can_scrub)
+    }
+
+    #[inline(always)]
+    fn scrub(&mut self, delta: &mut f64) -> bool {
+        self.as_mut().is_some_and(|value| value.scrub(delta))
+    }
+
+    #[inline(always)]
+    fn constrain(&mut self) -> Result<(), String> {
+        self.as_mut().map_or(Ok(()), T::constrain)
+    }
+
+    #[inline(always)]
+    fn item_label(&self) -> Option<String> {
+        self.as_ref().and_then(T::item_label)
+    }
+
+    #[inline(always)]
+    fn header_mode(&self) -> HeaderMode {
+        self.as_ref().map_or(HeaderMode::Collapsed, T::header_mode)
+    }
 }
 
 #[inline(always)]
@@ -1,6 +1,6 @@
 use crate::{
     EguiProbe,
-    collections::{DeleteMe, EguiProbeFrozen},
+    collections::{DeleteMe, EguiProbeFrozen, ItemKeys, item_label},
     option::option_probe_with,
 };
 
@@ -34,16 +34,22 @@ where
         ui: &mut egui::Ui,
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
+        let mut keys = ItemKeys::load(ui, self.len());
         let mut idx = 0;
         self.retain_mut(|value| {
             let mut item = DeleteMe {
                 value,
                 delete: false,
             };
-            f(&format!("[{idx}]"), ui, &mut item);
+            let key = keys.get(idx);
+            ui.push_id(key, |ui| f(&item_label(idx, &*item.value), ui, &mut item));
+            if item.delete {
+                keys.remove(key);
+            }
             idx += 1;
             !item.delete
         });
+        keys.store(ui);
     }
 }
 
@@ -62,7 +68,7 @@ where
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
         for (i, value) in self.value.iter_mut().enumerate() {
-            f(&format!("[{i}]"), ui, value);
+            f(&item_label(i, &*value), ui, value);
         }
     }
 }
@@ -89,7 +95,7 @@ where
     ) {
         if let Some(vec) = self.value {
             for (i, value) in vec.iter_mut().enumerate() {
-                f(&format!("[{i}]"), ui, value);
+                f(&item_label(i, &*value), ui, value);
             }
         }
     }
@@ -125,16 +131,22 @@ where
         ui: &mut egui::Ui,
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
+        let mut keys = ItemKeys::load(ui, self.len());
         let mut idx = 0;
         self.retain_mut(|value| {
             let mut item = DeleteMe {
                 value,
                 delete: false,
             };
-            f(&format!("[{idx}]"), ui, &mut item);
+            let key = keys.get(idx);
+            ui.push_id(key, |ui| f(&item_label(idx, &*item.value), ui, &mut item));
+            if item.delete {
+                keys.remove(key);
+            }
             idx += 1;
             !item.delete
         });
+        keys.store(ui);
     }
 }
 
@@ -153,7 +165,7 @@ where
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
         for (i, value) in self.value.iter_mut().enumerate() {
-            f(&format!("[{i}]"), ui, value);
+            f(&item_label(i, &*value), ui, value);
         }
     }
 }
@@ -180,7 +192,7 @@ where
     ) {
         if let Some(vec) = self.value {
             for (i, value) in vec.iter_mut().enumerate() {
-                f(&format!("[{i}]"), ui, value);
+                f(&item_label(i, &*value), ui, value);
             }
         }
     }
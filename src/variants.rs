@@ -0,0 +1,295 @@
+use core::marker::PhantomData;
+
+use crate::VariantsStyle;
+
+#[derive(Clone, Copy)]
+enum VariantWidget {
+    Selectable,
+    Radio,
+    Segment,
+}
+
+/// Adds widgets to select enum variants in the style chosen by [`variants_selector`].
+pub struct VariantSelector<'a> {
+    widget: VariantWidget,
+
+    /// Variants with names not containing this string are not shown.
+    filter: Option<&'a str>,
+
+    /// Whether categories are shown as submenus.
+    submenus: bool,
+}
+
+impl VariantSelector<'_> {
+    /// Shows widget for the variant with given name.
+    /// Disabled variant is shown greyed out and cannot be clicked.
+    ///
+    /// Returns `true` if the variant was clicked.
+    pub fn variant(&self, ui: &mut egui::Ui, checked: bool, enabled: bool, name: &str) -> bool {
+        if let Some(filter) = self.filter
+            && !name.to_lowercase().contains(&filter.to_lowercase())
+        {
+            return false;
+        }
+
+        let r = match self.widget {
+            VariantWidget::Selectable => {
+                ui.add_enabled(enabled, egui::Button::selectable(checked, name))
+            }
+            VariantWidget::Radio => ui.add_enabled(enabled, egui::RadioButton::new(checked, name)),
+            VariantWidget::Segment => {
+                ui.add_enabled(enabled, egui::Button::new(name).selected(checked))
+            }
+        };
+
+        r.clicked()
+    }
+
+    /// Shows variants of a category added by `add_variants`.
+    ///
+    /// In comboboxes category is shown as a submenu,
+    /// unless variants are filtered by search, then matching variants of all categories are shown together.
+    /// Otherwise variants of the category are shown after a separator.
+    pub fn category(
+        &self,
+        ui: &mut egui::Ui,
+        name: &str,
+        add_variants: impl FnOnce(&mut egui::Ui, &VariantSelector),
+    ) {
+        if self.filter.is_some_and(|filter| !filter.is_empty()) {
+            add_variants(ui, self);
+        } else if self.submenus {
+            ui.menu_button(name, |ui| add_variants(ui, self));
+        } else {
+            ui.separator();
+            add_variants(ui, self);
+        }
+    }
+}
+
+/// Shows selector of enum variants.
+///
+/// `add_variants` is called with the [`VariantSelector`] to add all variants.
+/// `selected` is the name of the current variant shown when variants are collapsed into a combobox.
+pub fn variants_selector(
+    ui: &mut egui::Ui,
+    style: VariantsStyle,
+    selected: &str,
+    add_variants: impl FnOnce(&mut egui::Ui, &VariantSelector),
+) {
+    let selector = |widget| VariantSelector {
+        widget,
+        filter: None,
+        submenus: false,
+    };
+
+    match style {
+        VariantsStyle::Inlined => add_variants(ui, &selector(VariantWidget::Selectable)),
+        VariantsStyle::Radio => add_variants(ui, &selector(VariantWidget::Radio)),
+        VariantsStyle::Segmented => {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                add_variants(ui, &selector(VariantWidget::Segment));
+            });
+        }
+        VariantsStyle::ComboBox => {
+            egui::ComboBox::from_id_salt(ui.make_persistent_id("cbox"))
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    add_variants(
+                        ui,
+                        &VariantSelector {
+                            widget: VariantWidget::Selectable,
+                            filter: None,
+                            submenus: true,
+                        },
+                    );
+                });
+        }
+        VariantsStyle::Searchable => {
+            let id = ui.make_persistent_id("cbox");
+            let search_id = id.with("search");
+
+            let r = egui::ComboBox::from_id_salt(id)
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    // Search text is stored only while popup is open.
+                    let stored = ui.data_mut(|d| d.get_temp::<String>(search_id));
+                    let opened = stored.is_none();
+                    let mut search = stored.unwrap_or_default();
+
+                    let r = ui.add(egui::TextEdit::singleline(&mut search).hint_text("Search"));
+                    if opened {
+                        r.request_focus();
+                    }
+
+                    ui.separator();
+
+                    add_variants(
+                        ui,
+                        &VariantSelector {
+                            widget: VariantWidget::Selectable,
+                            filter: Some(&search),
+                            submenus: true,
+                        },
+                    );
+
+                    ui.data_mut(|d| d.insert_temp(search_id, search));
+                });
+
+            if r.inner.is_none() {
+                ui.data_mut(|d| d.remove::<String>(search_id));
+            }
+        }
+    }
+}
+
+/// Provides default value for a field of constructed variant if field's type implements [`Default`].
+///
+/// Uses autoref-based specialization: `(&MaybeDefault::<T>::new()).variant_field_default()`
+/// resolves to [`ViaDefault`] if `T: Default` and to [`ViaNoDefault`] otherwise.
+pub struct MaybeDefault<T>(PhantomData<fn() -> T>);
+
+impl<T> MaybeDefault<T> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        MaybeDefault(PhantomData)
+    }
+}
+
+impl<T> Default for MaybeDefault<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ViaDefault<T> {
+    fn variant_field_has_default(&self) -> bool;
+    fn variant_field_default(&self) -> Option<T>;
+}
+
+impl<T> ViaDefault<T> for MaybeDefault<T>
+where
+    T: Default,
+{
+    #[inline(always)]
+    fn variant_field_has_default(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn variant_field_default(&self) -> Option<T> {
+        Some(T::default())
+    }
+}
+
+pub trait ViaNoDefault<T> {
+    fn variant_field_has_default(&self) -> bool;
+    fn variant_field_default(&self) -> Option<T>;
+}
+
+impl<T> ViaNoDefault<T> for &MaybeDefault<T> {
+    #[inline(always)]
+    fn variant_field_has_default(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn variant_field_default(&self) -> Option<T> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EguiProbe, Style,
+        collections::tests::{with_input, with_ui},
+    };
+
+    #[derive(Clone, EguiProbe)]
+    #[egui_probe(tags inlined, remember_variants)]
+    enum Shape {
+        Circle { radius: f32, color: u8 },
+        Square { side: f32, color: u8 },
+    }
+
+    /// Shows the value and clicks `idx`-th clickable widget of its row, e.g. a variant label.
+    fn click(ctx: &egui::Context, value: &mut dyn EguiProbe, idx: usize) {
+        let style = Style::default();
+
+        let mut row = egui::Rect::NOTHING;
+        with_ui(ctx, |ui| row = value.probe(ui, &style).rect);
+
+        let target = ctx.viewport(|viewport| {
+            viewport
+                .prev_pass
+                .widgets
+                .get_layer(egui::LayerId::background())
+                .filter(|widget| widget.sense.senses_click() && row.contains_rect(widget.rect))
+                .nth(idx)
+                .map(|widget| widget.rect.center())
+        });
+        let target = target.expect("clickable widget");
+
+        for pressed in [true, false] {
+            let input = egui::RawInput {
+                events: vec![
+                    egui::Event::PointerMoved(target),
+                    egui::Event::PointerButton {
+                        pos: target,
+                        button: egui::PointerButton::Primary,
+                        pressed,
+                        modifiers: egui::Modifiers::NONE,
+                    },
+                ],
+                ..egui::RawInput::default()
+            };
+            with_input(ctx, input, |ui| {
+                value.probe(ui, &style);
+            });
+        }
+    }
+
+    #[test]
+    fn switched_variants_carry_and_restore_fields() {
+        let ctx = egui::Context::default();
+        let mut shape = Shape::Circle {
+            radius: 2.0,
+            color: 7,
+        };
+
+        click(&ctx, &mut shape, 1);
+        assert!(matches!(
+            shape,
+            Shape::Square {
+                side: 0.0,
+                color: 7
+            }
+        ));
+
+        if let Shape::Square { side, color } = &mut shape {
+            *side = 3.0;
+            *color = 9;
+        }
+
+        click(&ctx, &mut shape, 0);
+        assert!(matches!(
+            shape,
+            Shape::Circle {
+                radius: 2.0,
+                color: 9
+            }
+        ));
+
+        click(&ctx, &mut shape, 1);
+        assert!(matches!(
+            shape,
+            Shape::Square {
+                side: 3.0,
+                color: 9
+            }
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     EguiProbe,
-    collections::{DeleteMe, EguiProbeFrozen},
+    collections::{DeleteMe, EguiProbeFrozen, ItemKeys, item_label},
     option::option_probe_with,
 };
 
@@ -24,16 +24,22 @@ where
         ui: &mut egui::Ui,
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
+        let mut keys = ItemKeys::load(ui, self.len());
         let mut idx = 0;
         self.retain_mut(|value| {
             let mut item = DeleteMe {
                 value,
                 delete: false,
             };
-            f(&format!("[{idx}]"), ui, &mut item);
+            let key = keys.get(idx);
+            ui.push_id(key, |ui| f(&item_label(idx, &*item.value), ui, &mut item));
+            if item.delete {
+                keys.remove(key);
+            }
             idx += 1;
             !item.delete
         });
+        keys.store(ui);
     }
 }
 
@@ -51,7 +57,7 @@ where
         f: &mut dyn FnMut(&str, &mut egui::Ui, &mut dyn EguiProbe),
     ) {
         for (i, value) in self.value.iter_mut().enumerate() {
-            f(&format!("[{i}]"), ui, value);
+            f(&item_label(i, &*value), ui, value);
         }
     }
 }
@@ -73,7 +79,7 @@ where
     ) {
         if let Some(vec) = self.value {
             for (i, value) in vec.iter_mut().enumerate() {
-                f(&format!("[{i}]"), ui, value);
+                f(&item_label(i, &*value), ui, value);
             }
         }
     }
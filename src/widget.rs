@@ -2,7 +2,7 @@ use core::hash::Hash;
 
 use egui::WidgetText;
 
-use crate::{EguiProbe, Style};
+use crate::{EguiProbe, HeaderMode, Style};
 
 #[derive(Clone, Copy)]
 struct ProbeHeaderState {
@@ -14,23 +14,30 @@ struct ProbeHeaderState {
 struct ProbeHeader {
     id: egui::Id,
     state: ProbeHeaderState,
+    collapsible: bool,
     dirty: bool,
     openness: f32,
 }
 
 impl ProbeHeader {
-    fn load(cx: &egui::Context, id: egui::Id) -> ProbeHeader {
-        let state = cx.data_mut(|d| d.get_temp(id)).unwrap_or(ProbeHeaderState {
+    fn load(cx: &egui::Context, id: egui::Id, mode: HeaderMode) -> ProbeHeader {
+        let mut state = cx.data_mut(|d| d.get_temp(id)).unwrap_or(ProbeHeaderState {
             has_inner: false,
-            open: false,
+            open: mode != HeaderMode::Collapsed,
             body_height: 0.0,
         });
 
+        let collapsible = mode != HeaderMode::NoHeader;
+        if !collapsible {
+            state.open = true;
+        }
+
         let openness = cx.animate_bool(id, state.open);
 
         ProbeHeader {
             id,
             state,
+            collapsible,
             dirty: false,
             openness,
         }
@@ -85,6 +92,19 @@ impl ProbeHeader {
     }
 }
 
+/// Loads error reported by [`EguiProbe::constrain`] for the value with given id.
+pub(crate) fn load_error(cx: &egui::Context, id: egui::Id) -> Option<String> {
+    cx.data(|d| d.get_temp(id.with("constrain")))
+}
+
+pub(crate) fn store_error(cx: &egui::Context, id: egui::Id, error: Option<String>) {
+    let id = id.with("constrain");
+    cx.data_mut(|d| match error {
+        Some(error) => d.insert_temp(id, error),
+        None => d.remove::<String>(id),
+    });
+}
+
 #[derive(Clone, Copy)]
 struct ProbeLayoutState {
     labels_width: f32,
@@ -209,6 +229,12 @@ where
         self
     }
 
+    /// Sets style used to show the value.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
     /// Show probbing UI to edit the value.
     pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
         let mut changed = false;
@@ -249,13 +275,7 @@ where
                             &mut changed,
                         );
                     } else {
-                        let mut got_inner = false;
-
-                        self.value.iterate_inner(ui, &mut |_, _, _| {
-                            got_inner = true;
-                        });
-
-                        header.set_has_inner(got_inner);
+                        header.set_has_inner(has_inner(self.value, ui));
                     }
 
                     header.store(child_ui.ctx());
@@ -304,27 +324,136 @@ fn show_header(
 ) -> ProbeHeader {
     let id = ui.make_persistent_id(id_salt);
 
-    let mut header = ProbeHeader::load(ui.ctx(), id);
+    let mut header = ProbeHeader::load(ui.ctx(), id, value.header_mode());
+
+    let scrub = style.scrub_labels && value.can_scrub();
+    let mut header_changed = false;
 
     ui.horizontal(|ui| {
         let label_response = layout.inner_label_ui(indent, id.with("label"), ui, |ui| {
-            if header.has_inner() {
+            if header.has_inner() && header.collapsible {
                 header.collapse_button(ui);
             }
-            ui.label(label)
+
+            if scrub {
+                ui.add(
+                    egui::Label::new(label)
+                        .selectable(false)
+                        .sense(egui::Sense::drag()),
+                )
+                .on_hover_cursor(egui::CursorIcon::ResizeHorizontal)
+            } else {
+                ui.label(label)
+            }
         });
 
+        if scrub {
+            header_changed |= scrub_label(&label_response, value, id.with("scrub"), ui);
+        }
+
         layout.inner_value_ui(id.with("value"), ui, |ui| {
-            *changed |= value
+            header_changed |= value
                 .probe(ui, style)
                 .labelled_by(label_response.id)
                 .changed();
+
+            if let Some(error) = load_error(ui.ctx(), id) {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
         });
     });
 
+    // Header widget may change the value as a whole, e.g. switch enum variant.
+    if header_changed {
+        *changed = true;
+        store_error(ui.ctx(), id, value.constrain().err());
+    }
+
     header
 }
 
+/// Checks whether value has inner records without showing them.
+fn has_inner(value: &mut dyn EguiProbe, ui: &mut egui::Ui) -> bool {
+    let mut ui = ui.new_child(egui::UiBuilder::new().invisible());
+    let mut got_inner = false;
+    value.iterate_inner(&mut ui, &mut |_, _, _| {
+        got_inner = true;
+    });
+    got_inner
+}
+
+/// Assigns id salts to records of a table.
+///
+/// Records emitted into their own ui, e.g. with [`egui::Ui::push_id`],
+/// are identified by that ui and keep their state when preceding records are hidden.
+/// Other records are identified by their position.
+struct RecordKeys {
+    table_id: egui::Id,
+    seen: Vec<egui::Id>,
+    idx: usize,
+}
+
+impl RecordKeys {
+    const fn new(table_id: egui::Id) -> Self {
+        RecordKeys {
+            table_id,
+            seen: Vec::new(),
+            idx: 0,
+        }
+    }
+
+    fn next(&mut self, ui: &egui::Ui) -> Option<usize> {
+        let idx = self.idx;
+        self.idx += 1;
+
+        let id = ui.id();
+        if id == self.table_id || self.seen.contains(&id) {
+            return Some(idx);
+        }
+
+        self.seen.push(id);
+        None
+    }
+}
+
+/// Applies horizontal drag of the label to the value.
+///
+/// `Shift` makes steps ten times finer and `Ctrl` ten times coarser.
+/// Part of the drag not applied due to rounding is kept until drag stops.
+fn scrub_label(
+    label_response: &egui::Response,
+    value: &mut dyn EguiProbe,
+    id: egui::Id,
+    ui: &egui::Ui,
+) -> bool {
+    if !label_response.dragged() {
+        if label_response.drag_stopped() {
+            ui.data_mut(|d| d.remove::<f64>(id));
+        }
+        return false;
+    }
+
+    let modifiers = ui.input(|i| i.modifiers);
+    let mut delta = f64::from(label_response.drag_delta().x);
+    if modifiers.shift {
+        delta *= 0.1;
+    }
+    if modifiers.command {
+        delta *= 10.0;
+    }
+
+    if delta == 0.0 {
+        return false;
+    }
+
+    let pending = ui.data(|d| d.get_temp::<f64>(id)).unwrap_or(0.0);
+    let mut delta = pending + delta;
+    let changed = value.scrub(&mut delta);
+    ui.data_mut(|d| d.insert_temp(id, delta));
+
+    changed
+}
+
 #[allow(clippy::too_many_arguments)]
 fn show_table(
     value: &mut dyn EguiProbe,
@@ -355,10 +484,13 @@ fn show_table(
     );
 
     let mut got_inner = false;
-    let mut idx = 0;
+    let mut inner_changed = false;
+    let mut keys = RecordKeys::new(table_ui.id());
     value.iterate_inner(&mut table_ui, &mut |label, table_ui, value| {
         got_inner = true;
 
+        let key = keys.next(table_ui);
+
         let mut header = show_header(
             label,
             value,
@@ -366,8 +498,8 @@ fn show_table(
             indent + 1,
             table_ui,
             style,
-            idx,
-            changed,
+            key,
+            &mut inner_changed,
         );
 
         if header.openness > 0.0 {
@@ -378,26 +510,23 @@ fn show_table(
                 indent + 1,
                 table_ui,
                 style,
-                idx,
-                changed,
+                key,
+                &mut inner_changed,
             );
         } else {
-            let mut got_inner = false;
-
-            value.iterate_inner(ui, &mut |_, _, _| {
-                got_inner = true;
-            });
-
-            header.set_has_inner(got_inner);
+            header.set_has_inner(has_inner(value, ui));
         }
 
         header.store(table_ui.ctx());
-
-        idx += 1;
     });
 
     header.set_has_inner(got_inner);
 
+    if inner_changed {
+        *changed = true;
+        store_error(ui.ctx(), header.id, value.constrain().err());
+    }
+
     let final_table_rect = table_ui.min_rect();
 
     ui.advance_cursor_after_rect(final_table_rect);
@@ -431,10 +560,13 @@ fn show_table_direct(
     );
 
     let mut got_inner = false;
-    let mut idx = 0;
+    let mut inner_changed = false;
+    let mut keys = RecordKeys::new(table_ui.id());
     value.iterate_inner(&mut table_ui, &mut |label, table_ui, value| {
         got_inner = true;
 
+        let key = keys.next(table_ui);
+
         let mut header = show_header(
             label,
             value,
@@ -442,8 +574,8 @@ fn show_table_direct(
             indent + 1,
             table_ui,
             style,
-            idx,
-            changed,
+            key,
+            &mut inner_changed,
         );
 
         if header.openness > 0.0 {
@@ -454,24 +586,25 @@ fn show_table_direct(
                 indent + 1,
                 table_ui,
                 style,
-                idx,
-                changed,
+                key,
+                &mut inner_changed,
             );
         } else {
-            let mut got_inner = false;
-
-            value.iterate_inner(ui, &mut |_, _, _| {
-                got_inner = true;
-            });
-
-            header.set_has_inner(got_inner);
+            header.set_has_inner(has_inner(value, ui));
         }
 
         header.store(table_ui.ctx());
-
-        idx += 1;
     });
 
+    if inner_changed {
+        *changed = true;
+        store_error(ui.ctx(), table_ui.id(), value.constrain().err());
+    }
+
+    if let Some(error) = load_error(ui.ctx(), table_ui.id()) {
+        table_ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+
     let final_table_rect = table_ui.min_rect();
     ui.advance_cursor_after_rect(final_table_rect);
 }